@@ -0,0 +1,94 @@
+// Does ahead of time all the work that doesn't depend on the user: which
+// emojis go in each group and in which order, and the search index of
+// every language
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[path = "src/groups.rs"]
+mod groups;
+#[path = "src/index.rs"]
+mod index;
+
+fn write_catalog(out_dir: &Path) {
+    let mut catalog = String::from("pub static GROUPS: &[(&str, &[&str])] = &[\n");
+    for (group, _) in groups::SUBGROUPS {
+        // Toned forms and other genders are left out later on, along with the variants
+        let mut emojis = emoji::lookup_by_glyph::iter_emoji()
+            .filter(|e| e.group == *group && !e.is_variant)
+            .collect::<Vec<_>>();
+        emojis.sort_by_cached_key(|e| groups::grid_order(e));
+        // Unqualified glyphs are looked up to the same emoji, which is then seen twice
+        emojis.dedup_by_key(|e| e.glyph);
+
+        let glyphs = emojis
+            .iter()
+            .map(|e| format!("{:?}", e.glyph))
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(catalog, "    ({group:?}, &[{glyphs}]),").unwrap();
+    }
+    catalog.push_str("];\n");
+
+    fs::write(out_dir.join("catalog.rs"), catalog).unwrap();
+}
+
+fn write_indexes(out_dir: &Path, embed: bool) {
+    let mut indexes = String::from(
+        "pub type IndexFiles = &'static [(&'static str, &'static [u8])];\n\
+         pub static INDEXES: &[(&str, IndexFiles)] = &[\n",
+    );
+    // Without embedding, the indexes are installed along with bmoji instead.
+    // BMOJI_EMBEDDED_LANGS="en,es" leaves every other language out
+    let only = env::var("BMOJI_EMBEDDED_LANGS").ok();
+    let langs = emoji::ANNOTATION_LANGS_AVAILABLE
+        .iter()
+        .copied()
+        .filter(|lang| {
+            embed
+                && only
+                    .as_ref()
+                    .is_none_or(|only| only.split(',').any(|l| l.trim() == *lang))
+        })
+        .collect::<Vec<_>>();
+    index::write_indexes(&out_dir.join("index"), langs.iter().copied()).unwrap();
+
+    for lang in langs {
+        let index_dir = out_dir.join("index").join(lang);
+        writeln!(indexes, "    ({lang:?}, &[").unwrap();
+        for entry in fs::read_dir(&index_dir).unwrap() {
+            let entry = entry.unwrap();
+            let name = entry.file_name().into_string().unwrap();
+            // Lock files are of no use for a read-only index
+            if name.starts_with(".tantivy-") {
+                continue;
+            }
+            writeln!(
+                indexes,
+                "        ({name:?}, include_bytes!({:?})),",
+                entry.path()
+            )
+            .unwrap();
+        }
+        indexes.push_str("    ]),\n");
+    }
+    indexes.push_str("];\n");
+
+    fs::write(out_dir.join("indexes.rs"), indexes).unwrap();
+}
+
+fn main() {
+    for source in ["build.rs", "src/groups.rs", "src/index.rs"] {
+        println!("cargo::rerun-if-changed={source}");
+    }
+    println!("cargo::rerun-if-env-changed=BMOJI_EMBEDDED_LANGS");
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    write_catalog(&out_dir);
+    write_indexes(
+        &out_dir,
+        env::var_os("CARGO_FEATURE_EMBEDDED_INDEX").is_some(),
+    );
+}
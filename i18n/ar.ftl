@@ -0,0 +1,124 @@
+search-placeholder = بحث...
+searching = جارٍ البحث...
+nothing-found = لم يُعثر على شيء
+history-empty = ستظهر هنا الرموز التعبيرية التي تستخدمها
+results = { $total ->
+    [zero] لا نتائج
+    [one] نتيجة واحدة
+    [two] نتيجتان
+    [few] { $total } نتائج
+   *[other] { $total } نتيجة
+}
+results-shown = { $shown } من { $total } نتيجة
+copy = نسخ
+search-language = لغة البحث
+
+category-history = المستخدمة مؤخرًا
+category-smileys-and-emotion = الوجوه والمشاعر
+category-people-and-body = الناس والجسم
+category-animals-and-nature = الحيوانات والطبيعة
+category-food-and-drink = الطعام والشراب
+category-travel-and-places = السفر والأماكن
+category-activities = الأنشطة
+category-objects = الأشياء
+category-symbols = الرموز
+category-flags = الأعلام
+
+subgroup-face-smiling = وجوه مبتسمة
+subgroup-face-affection = مودة
+subgroup-face-tongue = لسان خارج
+subgroup-face-hand = وجوه بأيدٍ
+subgroup-face-neutral-skeptical = محايدة ومتشككة
+subgroup-face-sleepy = نعاس
+subgroup-face-unwell = توعك
+subgroup-face-hat = قبعات
+subgroup-face-glasses = نظارات
+subgroup-face-concerned = قلق
+subgroup-face-negative = سلبية
+subgroup-face-costume = تنكر
+subgroup-cat-face = وجوه قطط
+subgroup-monkey-face = وجوه قرود
+subgroup-emotion = مشاعر
+subgroup-hand-fingers-open = أيدٍ مفتوحة
+subgroup-hand-fingers-partial = إشارات بالأصابع
+subgroup-hand-single-finger = إشارة بإصبع
+subgroup-hand-fingers-closed = أيدٍ مغلقة
+subgroup-hands = يدان
+subgroup-hand-prop = أيدٍ تحمل أشياء
+subgroup-body-parts = أجزاء الجسم
+subgroup-person = أشخاص
+subgroup-person-gesture = إيماءات
+subgroup-person-role = مهن
+subgroup-person-fantasy = خيال
+subgroup-person-activity = أنشطة
+subgroup-person-sport = رياضيون
+subgroup-person-resting = استراحة
+subgroup-family = عائلات
+subgroup-person-symbol = صور ظلية
+subgroup-animal-mammal = ثدييات
+subgroup-animal-bird = طيور
+subgroup-animal-amphibian = برمائيات
+subgroup-animal-reptile = زواحف
+subgroup-animal-marine = كائنات بحرية
+subgroup-animal-bug = حشرات
+subgroup-plant-flower = زهور
+subgroup-plant-other = نباتات
+subgroup-food-fruit = فواكه
+subgroup-food-vegetable = خضروات
+subgroup-food-prepared = أطباق
+subgroup-food-asian = طعام آسيوي
+subgroup-food-marine = مأكولات بحرية
+subgroup-food-sweet = حلويات
+subgroup-drink = مشروبات
+subgroup-dishware = أواني
+subgroup-place-map = خرائط
+subgroup-place-geographic = مناظر طبيعية
+subgroup-place-building = مبانٍ
+subgroup-place-religious = دور عبادة
+subgroup-place-other = أماكن أخرى
+subgroup-transport-ground = نقل بري
+subgroup-transport-water = نقل بحري
+subgroup-transport-air = نقل جوي
+subgroup-hotel = فندق
+subgroup-time = الوقت
+subgroup-sky-and-weather = السماء والطقس
+subgroup-event = مناسبات
+subgroup-award-medal = جوائز
+subgroup-sport = رياضة
+subgroup-game = ألعاب
+subgroup-arts-and-crafts = فنون وحرف
+subgroup-clothing = ملابس
+subgroup-sound = صوت
+subgroup-music = موسيقى
+subgroup-musical-instrument = آلات موسيقية
+subgroup-phone = هواتف
+subgroup-computer = حواسيب
+subgroup-light-and-video = إضاءة وفيديو
+subgroup-book-paper = كتب وورق
+subgroup-money = نقود
+subgroup-mail = بريد
+subgroup-writing = كتابة
+subgroup-office = مكتب
+subgroup-lock = أقفال
+subgroup-tool = أدوات
+subgroup-science = علوم
+subgroup-medical = طب
+subgroup-household = منزل
+subgroup-other-object = أشياء أخرى
+subgroup-transport-sign = إشارات المرور
+subgroup-warning = تحذيرات
+subgroup-arrow = أسهم
+subgroup-religion = دين
+subgroup-zodiac = الأبراج
+subgroup-av-symbol = صوت وفيديو
+subgroup-gender = الجنس
+subgroup-math = رياضيات
+subgroup-punctuation = علامات الترقيم
+subgroup-currency = عملات
+subgroup-other-symbol = رموز أخرى
+subgroup-keycap = مفاتيح
+subgroup-alphanum = حروف وأرقام
+subgroup-geometric = أشكال
+subgroup-flag = أعلام
+subgroup-country-flag = دول
+subgroup-subdivision-flag = مناطق
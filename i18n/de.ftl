@@ -0,0 +1,121 @@
+search-placeholder = Suchen...
+searching = Suche läuft...
+nothing-found = Nichts gefunden
+history-empty = Verwendete Emojis erscheinen hier
+results = { $total ->
+    [one] { $total } Ergebnis
+   *[other] { $total } Ergebnisse
+}
+results-shown = { $shown } von { $total } Ergebnissen
+copy = Kopieren
+search-language = Suchsprache
+
+category-history = Zuletzt verwendet
+category-smileys-and-emotion = Smileys & Emotionen
+category-people-and-body = Menschen & Körper
+category-animals-and-nature = Tiere & Natur
+category-food-and-drink = Essen & Trinken
+category-travel-and-places = Reisen & Orte
+category-activities = Aktivitäten
+category-objects = Objekte
+category-symbols = Symbole
+category-flags = Flaggen
+
+subgroup-face-smiling = Lächelnde Gesichter
+subgroup-face-affection = Zuneigung
+subgroup-face-tongue = Zunge raus
+subgroup-face-hand = Gesichter mit Händen
+subgroup-face-neutral-skeptical = Neutral & skeptisch
+subgroup-face-sleepy = Müde
+subgroup-face-unwell = Unwohl
+subgroup-face-hat = Hüte
+subgroup-face-glasses = Brillen
+subgroup-face-concerned = Besorgt
+subgroup-face-negative = Negativ
+subgroup-face-costume = Kostüme
+subgroup-cat-face = Katzengesichter
+subgroup-monkey-face = Affengesichter
+subgroup-emotion = Gefühle
+subgroup-hand-fingers-open = Offene Hände
+subgroup-hand-fingers-partial = Fingerzeichen
+subgroup-hand-single-finger = Zeigefinger
+subgroup-hand-fingers-closed = Geschlossene Hände
+subgroup-hands = Hände
+subgroup-hand-prop = Hände mit Dingen
+subgroup-body-parts = Körperteile
+subgroup-person = Personen
+subgroup-person-gesture = Gesten
+subgroup-person-role = Berufe
+subgroup-person-fantasy = Fantasie
+subgroup-person-activity = Aktivitäten
+subgroup-person-sport = Sportler
+subgroup-person-resting = Ausruhen
+subgroup-family = Familien
+subgroup-person-symbol = Silhouetten
+subgroup-animal-mammal = Säugetiere
+subgroup-animal-bird = Vögel
+subgroup-animal-amphibian = Amphibien
+subgroup-animal-reptile = Reptilien
+subgroup-animal-marine = Meerestiere
+subgroup-animal-bug = Insekten
+subgroup-plant-flower = Blumen
+subgroup-plant-other = Pflanzen
+subgroup-food-fruit = Obst
+subgroup-food-vegetable = Gemüse
+subgroup-food-prepared = Gerichte
+subgroup-food-asian = Asiatisches Essen
+subgroup-food-marine = Meeresfrüchte
+subgroup-food-sweet = Süßes
+subgroup-drink = Getränke
+subgroup-dishware = Geschirr
+subgroup-place-map = Karten
+subgroup-place-geographic = Landschaften
+subgroup-place-building = Gebäude
+subgroup-place-religious = Gotteshäuser
+subgroup-place-other = Andere Orte
+subgroup-transport-ground = Landverkehr
+subgroup-transport-water = Wasserverkehr
+subgroup-transport-air = Luftverkehr
+subgroup-hotel = Hotel
+subgroup-time = Zeit
+subgroup-sky-and-weather = Himmel & Wetter
+subgroup-event = Ereignisse
+subgroup-award-medal = Auszeichnungen
+subgroup-sport = Sport
+subgroup-game = Spiele
+subgroup-arts-and-crafts = Kunst & Handwerk
+subgroup-clothing = Kleidung
+subgroup-sound = Ton
+subgroup-music = Musik
+subgroup-musical-instrument = Instrumente
+subgroup-phone = Telefone
+subgroup-computer = Computer
+subgroup-light-and-video = Licht & Video
+subgroup-book-paper = Bücher & Papier
+subgroup-money = Geld
+subgroup-mail = Post
+subgroup-writing = Schreiben
+subgroup-office = Büro
+subgroup-lock = Schlösser
+subgroup-tool = Werkzeug
+subgroup-science = Wissenschaft
+subgroup-medical = Medizin
+subgroup-household = Haushalt
+subgroup-other-object = Andere Objekte
+subgroup-transport-sign = Verkehrszeichen
+subgroup-warning = Warnungen
+subgroup-arrow = Pfeile
+subgroup-religion = Religion
+subgroup-zodiac = Tierkreis
+subgroup-av-symbol = Audio & Video
+subgroup-gender = Geschlecht
+subgroup-math = Mathematik
+subgroup-punctuation = Satzzeichen
+subgroup-currency = Währungen
+subgroup-other-symbol = Andere Symbole
+subgroup-keycap = Tasten
+subgroup-alphanum = Buchstaben & Zahlen
+subgroup-geometric = Formen
+subgroup-flag = Flaggen
+subgroup-country-flag = Länder
+subgroup-subdivision-flag = Regionen
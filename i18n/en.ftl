@@ -0,0 +1,121 @@
+search-placeholder = Search...
+searching = Searching...
+nothing-found = Nothing found
+history-empty = Use emojis for them to appear here
+results = { $total ->
+    [one] { $total } result
+   *[other] { $total } results
+}
+results-shown = { $shown } of { $total } results
+copy = Copy
+search-language = Search language
+
+category-history = Recently used
+category-smileys-and-emotion = Smileys & Emotion
+category-people-and-body = People & Body
+category-animals-and-nature = Animals & Nature
+category-food-and-drink = Food & Drink
+category-travel-and-places = Travel & Places
+category-activities = Activities
+category-objects = Objects
+category-symbols = Symbols
+category-flags = Flags
+
+subgroup-face-smiling = Smiling faces
+subgroup-face-affection = Affection
+subgroup-face-tongue = Tongues out
+subgroup-face-hand = Faces with hands
+subgroup-face-neutral-skeptical = Neutral & skeptical
+subgroup-face-sleepy = Sleepy
+subgroup-face-unwell = Unwell
+subgroup-face-hat = Hats
+subgroup-face-glasses = Glasses
+subgroup-face-concerned = Concerned
+subgroup-face-negative = Negative
+subgroup-face-costume = Costumes
+subgroup-cat-face = Cat faces
+subgroup-monkey-face = Monkey faces
+subgroup-emotion = Emotions
+subgroup-hand-fingers-open = Open hands
+subgroup-hand-fingers-partial = Finger signs
+subgroup-hand-single-finger = Pointing
+subgroup-hand-fingers-closed = Closed hands
+subgroup-hands = Hands
+subgroup-hand-prop = Hands holding things
+subgroup-body-parts = Body parts
+subgroup-person = People
+subgroup-person-gesture = Gestures
+subgroup-person-role = Roles
+subgroup-person-fantasy = Fantasy
+subgroup-person-activity = Activities
+subgroup-person-sport = Athletes
+subgroup-person-resting = Resting
+subgroup-family = Families
+subgroup-person-symbol = Silhouettes
+subgroup-animal-mammal = Mammals
+subgroup-animal-bird = Birds
+subgroup-animal-amphibian = Amphibians
+subgroup-animal-reptile = Reptiles
+subgroup-animal-marine = Sea life
+subgroup-animal-bug = Bugs
+subgroup-plant-flower = Flowers
+subgroup-plant-other = Plants
+subgroup-food-fruit = Fruit
+subgroup-food-vegetable = Vegetables
+subgroup-food-prepared = Prepared food
+subgroup-food-asian = Asian food
+subgroup-food-marine = Seafood
+subgroup-food-sweet = Sweets
+subgroup-drink = Drinks
+subgroup-dishware = Dishware
+subgroup-place-map = Maps
+subgroup-place-geographic = Landscapes
+subgroup-place-building = Buildings
+subgroup-place-religious = Places of worship
+subgroup-place-other = Other places
+subgroup-transport-ground = Land transport
+subgroup-transport-water = Water transport
+subgroup-transport-air = Air transport
+subgroup-hotel = Hotel
+subgroup-time = Time
+subgroup-sky-and-weather = Sky & weather
+subgroup-event = Events
+subgroup-award-medal = Awards
+subgroup-sport = Sports
+subgroup-game = Games
+subgroup-arts-and-crafts = Arts & crafts
+subgroup-clothing = Clothing
+subgroup-sound = Sound
+subgroup-music = Music
+subgroup-musical-instrument = Instruments
+subgroup-phone = Phones
+subgroup-computer = Computers
+subgroup-light-and-video = Light & video
+subgroup-book-paper = Books & paper
+subgroup-money = Money
+subgroup-mail = Mail
+subgroup-writing = Writing
+subgroup-office = Office
+subgroup-lock = Locks
+subgroup-tool = Tools
+subgroup-science = Science
+subgroup-medical = Medical
+subgroup-household = Household
+subgroup-other-object = Other objects
+subgroup-transport-sign = Transport signs
+subgroup-warning = Warnings
+subgroup-arrow = Arrows
+subgroup-religion = Religion
+subgroup-zodiac = Zodiac
+subgroup-av-symbol = Audio & video
+subgroup-gender = Gender
+subgroup-math = Math
+subgroup-punctuation = Punctuation
+subgroup-currency = Currency
+subgroup-other-symbol = Other symbols
+subgroup-keycap = Keycaps
+subgroup-alphanum = Letters & numbers
+subgroup-geometric = Shapes
+subgroup-flag = Flags
+subgroup-country-flag = Countries
+subgroup-subdivision-flag = Regions
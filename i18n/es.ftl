@@ -0,0 +1,121 @@
+search-placeholder = Buscar...
+searching = Buscando...
+nothing-found = No se ha encontrado nada
+history-empty = Usa emojis para que aparezcan aquí
+results = { $total ->
+    [one] { $total } resultado
+   *[other] { $total } resultados
+}
+results-shown = { $shown } de { $total } resultados
+copy = Copiar
+search-language = Idioma de búsqueda
+
+category-history = Usados recientemente
+category-smileys-and-emotion = Caras y emociones
+category-people-and-body = Personas y cuerpo
+category-animals-and-nature = Animales y naturaleza
+category-food-and-drink = Comida y bebida
+category-travel-and-places = Viajes y lugares
+category-activities = Actividades
+category-objects = Objetos
+category-symbols = Símbolos
+category-flags = Banderas
+
+subgroup-face-smiling = Caras sonrientes
+subgroup-face-affection = Cariño
+subgroup-face-tongue = Lengua fuera
+subgroup-face-hand = Caras con manos
+subgroup-face-neutral-skeptical = Neutrales y escépticas
+subgroup-face-sleepy = Sueño
+subgroup-face-unwell = Malestar
+subgroup-face-hat = Sombreros
+subgroup-face-glasses = Gafas
+subgroup-face-concerned = Preocupación
+subgroup-face-negative = Negativas
+subgroup-face-costume = Disfraces
+subgroup-cat-face = Caras de gato
+subgroup-monkey-face = Caras de mono
+subgroup-emotion = Emociones
+subgroup-hand-fingers-open = Manos abiertas
+subgroup-hand-fingers-partial = Señas con dedos
+subgroup-hand-single-finger = Señalar
+subgroup-hand-fingers-closed = Manos cerradas
+subgroup-hands = Manos
+subgroup-hand-prop = Manos con objetos
+subgroup-body-parts = Partes del cuerpo
+subgroup-person = Personas
+subgroup-person-gesture = Gestos
+subgroup-person-role = Oficios
+subgroup-person-fantasy = Fantasía
+subgroup-person-activity = Actividades
+subgroup-person-sport = Deportistas
+subgroup-person-resting = Descanso
+subgroup-family = Familias
+subgroup-person-symbol = Siluetas
+subgroup-animal-mammal = Mamíferos
+subgroup-animal-bird = Aves
+subgroup-animal-amphibian = Anfibios
+subgroup-animal-reptile = Reptiles
+subgroup-animal-marine = Animales marinos
+subgroup-animal-bug = Bichos
+subgroup-plant-flower = Flores
+subgroup-plant-other = Plantas
+subgroup-food-fruit = Fruta
+subgroup-food-vegetable = Verduras
+subgroup-food-prepared = Platos
+subgroup-food-asian = Comida asiática
+subgroup-food-marine = Marisco
+subgroup-food-sweet = Dulces
+subgroup-drink = Bebidas
+subgroup-dishware = Vajilla
+subgroup-place-map = Mapas
+subgroup-place-geographic = Paisajes
+subgroup-place-building = Edificios
+subgroup-place-religious = Lugares de culto
+subgroup-place-other = Otros lugares
+subgroup-transport-ground = Transporte terrestre
+subgroup-transport-water = Transporte acuático
+subgroup-transport-air = Transporte aéreo
+subgroup-hotel = Hotel
+subgroup-time = Tiempo
+subgroup-sky-and-weather = Cielo y clima
+subgroup-event = Eventos
+subgroup-award-medal = Premios
+subgroup-sport = Deportes
+subgroup-game = Juegos
+subgroup-arts-and-crafts = Arte y manualidades
+subgroup-clothing = Ropa
+subgroup-sound = Sonido
+subgroup-music = Música
+subgroup-musical-instrument = Instrumentos
+subgroup-phone = Teléfonos
+subgroup-computer = Ordenadores
+subgroup-light-and-video = Luz y vídeo
+subgroup-book-paper = Libros y papel
+subgroup-money = Dinero
+subgroup-mail = Correo
+subgroup-writing = Escritura
+subgroup-office = Oficina
+subgroup-lock = Candados
+subgroup-tool = Herramientas
+subgroup-science = Ciencia
+subgroup-medical = Medicina
+subgroup-household = Hogar
+subgroup-other-object = Otros objetos
+subgroup-transport-sign = Señales de tráfico
+subgroup-warning = Avisos
+subgroup-arrow = Flechas
+subgroup-religion = Religión
+subgroup-zodiac = Zodiaco
+subgroup-av-symbol = Audio y vídeo
+subgroup-gender = Género
+subgroup-math = Matemáticas
+subgroup-punctuation = Puntuación
+subgroup-currency = Monedas
+subgroup-other-symbol = Otros símbolos
+subgroup-keycap = Teclas
+subgroup-alphanum = Letras y números
+subgroup-geometric = Formas
+subgroup-flag = Banderas
+subgroup-country-flag = Países
+subgroup-subdivision-flag = Regiones
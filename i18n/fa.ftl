@@ -0,0 +1,118 @@
+search-placeholder = جستجو...
+searching = در حال جستجو...
+nothing-found = چیزی پیدا نشد
+history-empty = شکلک‌هایی که استفاده کنید اینجا نمایش داده می‌شوند
+results = { $total } نتیجه
+results-shown = { $shown } از { $total } نتیجه
+copy = رونوشت
+search-language = زبان جستجو
+
+category-history = اخیراً استفاده‌شده
+category-smileys-and-emotion = صورتک‌ها و احساسات
+category-people-and-body = افراد و بدن
+category-animals-and-nature = حیوانات و طبیعت
+category-food-and-drink = غذا و نوشیدنی
+category-travel-and-places = سفر و مکان‌ها
+category-activities = فعالیت‌ها
+category-objects = اشیا
+category-symbols = نمادها
+category-flags = پرچم‌ها
+
+subgroup-face-smiling = چهره‌های خندان
+subgroup-face-affection = محبت
+subgroup-face-tongue = زبان بیرون
+subgroup-face-hand = چهره با دست
+subgroup-face-neutral-skeptical = خنثی و مردد
+subgroup-face-sleepy = خواب‌آلود
+subgroup-face-unwell = ناخوش
+subgroup-face-hat = کلاه‌ها
+subgroup-face-glasses = عینک‌ها
+subgroup-face-concerned = نگران
+subgroup-face-negative = منفی
+subgroup-face-costume = لباس مبدل
+subgroup-cat-face = چهرهٔ گربه
+subgroup-monkey-face = چهرهٔ میمون
+subgroup-emotion = احساسات
+subgroup-hand-fingers-open = دست‌های باز
+subgroup-hand-fingers-partial = اشاره با انگشتان
+subgroup-hand-single-finger = اشاره با یک انگشت
+subgroup-hand-fingers-closed = دست‌های بسته
+subgroup-hands = دست‌ها
+subgroup-hand-prop = دست با اشیا
+subgroup-body-parts = اعضای بدن
+subgroup-person = افراد
+subgroup-person-gesture = حرکات
+subgroup-person-role = مشاغل
+subgroup-person-fantasy = خیالی
+subgroup-person-activity = فعالیت‌ها
+subgroup-person-sport = ورزشکاران
+subgroup-person-resting = استراحت
+subgroup-family = خانواده‌ها
+subgroup-person-symbol = سایه‌نما
+subgroup-animal-mammal = پستانداران
+subgroup-animal-bird = پرندگان
+subgroup-animal-amphibian = دوزیستان
+subgroup-animal-reptile = خزندگان
+subgroup-animal-marine = جانوران دریایی
+subgroup-animal-bug = حشرات
+subgroup-plant-flower = گل‌ها
+subgroup-plant-other = گیاهان
+subgroup-food-fruit = میوه‌ها
+subgroup-food-vegetable = سبزیجات
+subgroup-food-prepared = غذاها
+subgroup-food-asian = غذای آسیایی
+subgroup-food-marine = غذای دریایی
+subgroup-food-sweet = شیرینی‌ها
+subgroup-drink = نوشیدنی‌ها
+subgroup-dishware = ظروف
+subgroup-place-map = نقشه‌ها
+subgroup-place-geographic = چشم‌اندازها
+subgroup-place-building = ساختمان‌ها
+subgroup-place-religious = اماکن مذهبی
+subgroup-place-other = مکان‌های دیگر
+subgroup-transport-ground = حمل‌ونقل زمینی
+subgroup-transport-water = حمل‌ونقل آبی
+subgroup-transport-air = حمل‌ونقل هوایی
+subgroup-hotel = هتل
+subgroup-time = زمان
+subgroup-sky-and-weather = آسمان و هوا
+subgroup-event = رویدادها
+subgroup-award-medal = جوایز
+subgroup-sport = ورزش
+subgroup-game = بازی‌ها
+subgroup-arts-and-crafts = هنر و صنایع دستی
+subgroup-clothing = پوشاک
+subgroup-sound = صدا
+subgroup-music = موسیقی
+subgroup-musical-instrument = سازها
+subgroup-phone = تلفن‌ها
+subgroup-computer = رایانه‌ها
+subgroup-light-and-video = نور و ویدیو
+subgroup-book-paper = کتاب و کاغذ
+subgroup-money = پول
+subgroup-mail = پست
+subgroup-writing = نوشتن
+subgroup-office = اداری
+subgroup-lock = قفل‌ها
+subgroup-tool = ابزارها
+subgroup-science = علوم
+subgroup-medical = پزشکی
+subgroup-household = خانه
+subgroup-other-object = اشیای دیگر
+subgroup-transport-sign = تابلوهای راهنمایی
+subgroup-warning = هشدارها
+subgroup-arrow = پیکان‌ها
+subgroup-religion = دین
+subgroup-zodiac = صورت‌های فلکی
+subgroup-av-symbol = صوتی و تصویری
+subgroup-gender = جنسیت
+subgroup-math = ریاضی
+subgroup-punctuation = نشانه‌گذاری
+subgroup-currency = ارزها
+subgroup-other-symbol = نمادهای دیگر
+subgroup-keycap = کلیدها
+subgroup-alphanum = حروف و اعداد
+subgroup-geometric = اشکال
+subgroup-flag = پرچم‌ها
+subgroup-country-flag = کشورها
+subgroup-subdivision-flag = مناطق
@@ -0,0 +1,121 @@
+search-placeholder = Rechercher...
+searching = Recherche...
+nothing-found = Aucun résultat
+history-empty = Utilisez des emojis pour qu’ils apparaissent ici
+results = { $total ->
+    [one] { $total } résultat
+   *[other] { $total } résultats
+}
+results-shown = { $shown } sur { $total } résultats
+copy = Copier
+search-language = Langue de recherche
+
+category-history = Utilisés récemment
+category-smileys-and-emotion = Smileys et émotions
+category-people-and-body = Personnes et corps
+category-animals-and-nature = Animaux et nature
+category-food-and-drink = Nourriture et boissons
+category-travel-and-places = Voyages et lieux
+category-activities = Activités
+category-objects = Objets
+category-symbols = Symboles
+category-flags = Drapeaux
+
+subgroup-face-smiling = Visages souriants
+subgroup-face-affection = Affection
+subgroup-face-tongue = Langue tirée
+subgroup-face-hand = Visages avec mains
+subgroup-face-neutral-skeptical = Neutres et sceptiques
+subgroup-face-sleepy = Fatigue
+subgroup-face-unwell = Malaise
+subgroup-face-hat = Chapeaux
+subgroup-face-glasses = Lunettes
+subgroup-face-concerned = Inquiétude
+subgroup-face-negative = Négatifs
+subgroup-face-costume = Déguisements
+subgroup-cat-face = Têtes de chat
+subgroup-monkey-face = Têtes de singe
+subgroup-emotion = Émotions
+subgroup-hand-fingers-open = Mains ouvertes
+subgroup-hand-fingers-partial = Signes des doigts
+subgroup-hand-single-finger = Index
+subgroup-hand-fingers-closed = Mains fermées
+subgroup-hands = Mains
+subgroup-hand-prop = Mains tenant des objets
+subgroup-body-parts = Parties du corps
+subgroup-person = Personnes
+subgroup-person-gesture = Gestes
+subgroup-person-role = Métiers
+subgroup-person-fantasy = Fantastique
+subgroup-person-activity = Activités
+subgroup-person-sport = Sportifs
+subgroup-person-resting = Repos
+subgroup-family = Familles
+subgroup-person-symbol = Silhouettes
+subgroup-animal-mammal = Mammifères
+subgroup-animal-bird = Oiseaux
+subgroup-animal-amphibian = Amphibiens
+subgroup-animal-reptile = Reptiles
+subgroup-animal-marine = Animaux marins
+subgroup-animal-bug = Insectes
+subgroup-plant-flower = Fleurs
+subgroup-plant-other = Plantes
+subgroup-food-fruit = Fruits
+subgroup-food-vegetable = Légumes
+subgroup-food-prepared = Plats
+subgroup-food-asian = Cuisine asiatique
+subgroup-food-marine = Fruits de mer
+subgroup-food-sweet = Sucreries
+subgroup-drink = Boissons
+subgroup-dishware = Vaisselle
+subgroup-place-map = Cartes
+subgroup-place-geographic = Paysages
+subgroup-place-building = Bâtiments
+subgroup-place-religious = Lieux de culte
+subgroup-place-other = Autres lieux
+subgroup-transport-ground = Transport terrestre
+subgroup-transport-water = Transport maritime
+subgroup-transport-air = Transport aérien
+subgroup-hotel = Hôtel
+subgroup-time = Temps
+subgroup-sky-and-weather = Ciel et météo
+subgroup-event = Évènements
+subgroup-award-medal = Récompenses
+subgroup-sport = Sports
+subgroup-game = Jeux
+subgroup-arts-and-crafts = Arts et loisirs créatifs
+subgroup-clothing = Vêtements
+subgroup-sound = Son
+subgroup-music = Musique
+subgroup-musical-instrument = Instruments
+subgroup-phone = Téléphones
+subgroup-computer = Ordinateurs
+subgroup-light-and-video = Lumière et vidéo
+subgroup-book-paper = Livres et papier
+subgroup-money = Argent
+subgroup-mail = Courrier
+subgroup-writing = Écriture
+subgroup-office = Bureau
+subgroup-lock = Cadenas
+subgroup-tool = Outils
+subgroup-science = Science
+subgroup-medical = Médecine
+subgroup-household = Maison
+subgroup-other-object = Autres objets
+subgroup-transport-sign = Signalisation
+subgroup-warning = Avertissements
+subgroup-arrow = Flèches
+subgroup-religion = Religion
+subgroup-zodiac = Zodiaque
+subgroup-av-symbol = Audio et vidéo
+subgroup-gender = Genre
+subgroup-math = Mathématiques
+subgroup-punctuation = Ponctuation
+subgroup-currency = Devises
+subgroup-other-symbol = Autres symboles
+subgroup-keycap = Touches
+subgroup-alphanum = Lettres et chiffres
+subgroup-geometric = Formes
+subgroup-flag = Drapeaux
+subgroup-country-flag = Pays
+subgroup-subdivision-flag = Régions
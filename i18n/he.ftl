@@ -0,0 +1,121 @@
+search-placeholder = חיפוש...
+searching = מחפש...
+nothing-found = לא נמצא דבר
+history-empty = אמוג׳ים שבשימוש יופיעו כאן
+results = { $total ->
+    [one] תוצאה אחת
+   *[other] { $total } תוצאות
+}
+results-shown = { $shown } מתוך { $total } תוצאות
+copy = העתקה
+search-language = שפת החיפוש
+
+category-history = בשימוש לאחרונה
+category-smileys-and-emotion = סמיילים ורגשות
+category-people-and-body = אנשים וגוף
+category-animals-and-nature = בעלי חיים וטבע
+category-food-and-drink = אוכל ושתייה
+category-travel-and-places = טיולים ומקומות
+category-activities = פעילויות
+category-objects = חפצים
+category-symbols = סמלים
+category-flags = דגלים
+
+subgroup-face-smiling = פרצופים מחייכים
+subgroup-face-affection = חיבה
+subgroup-face-tongue = לשון בחוץ
+subgroup-face-hand = פרצופים עם ידיים
+subgroup-face-neutral-skeptical = ניטרליים וספקניים
+subgroup-face-sleepy = ישנוניות
+subgroup-face-unwell = חולי
+subgroup-face-hat = כובעים
+subgroup-face-glasses = משקפיים
+subgroup-face-concerned = דאגה
+subgroup-face-negative = שליליים
+subgroup-face-costume = תחפושות
+subgroup-cat-face = פרצופי חתול
+subgroup-monkey-face = פרצופי קוף
+subgroup-emotion = רגשות
+subgroup-hand-fingers-open = ידיים פתוחות
+subgroup-hand-fingers-partial = סימני אצבעות
+subgroup-hand-single-finger = הצבעה
+subgroup-hand-fingers-closed = ידיים סגורות
+subgroup-hands = זוג ידיים
+subgroup-hand-prop = ידיים מחזיקות חפצים
+subgroup-body-parts = איברי גוף
+subgroup-person = אנשים
+subgroup-person-gesture = מחוות
+subgroup-person-role = מקצועות
+subgroup-person-fantasy = פנטזיה
+subgroup-person-activity = פעילויות
+subgroup-person-sport = ספורטאים
+subgroup-person-resting = מנוחה
+subgroup-family = משפחות
+subgroup-person-symbol = צלליות
+subgroup-animal-mammal = יונקים
+subgroup-animal-bird = ציפורים
+subgroup-animal-amphibian = דו-חיים
+subgroup-animal-reptile = זוחלים
+subgroup-animal-marine = חיות ים
+subgroup-animal-bug = חרקים
+subgroup-plant-flower = פרחים
+subgroup-plant-other = צמחים
+subgroup-food-fruit = פירות
+subgroup-food-vegetable = ירקות
+subgroup-food-prepared = מנות
+subgroup-food-asian = אוכל אסייתי
+subgroup-food-marine = פירות ים
+subgroup-food-sweet = מתוקים
+subgroup-drink = משקאות
+subgroup-dishware = כלי אוכל
+subgroup-place-map = מפות
+subgroup-place-geographic = נופים
+subgroup-place-building = בניינים
+subgroup-place-religious = מקומות תפילה
+subgroup-place-other = מקומות אחרים
+subgroup-transport-ground = תחבורה יבשתית
+subgroup-transport-water = תחבורה ימית
+subgroup-transport-air = תחבורה אווירית
+subgroup-hotel = מלון
+subgroup-time = זמן
+subgroup-sky-and-weather = שמיים ומזג אוויר
+subgroup-event = אירועים
+subgroup-award-medal = פרסים
+subgroup-sport = ספורט
+subgroup-game = משחקים
+subgroup-arts-and-crafts = אמנות ויצירה
+subgroup-clothing = ביגוד
+subgroup-sound = צליל
+subgroup-music = מוזיקה
+subgroup-musical-instrument = כלי נגינה
+subgroup-phone = טלפונים
+subgroup-computer = מחשבים
+subgroup-light-and-video = תאורה ווידאו
+subgroup-book-paper = ספרים ונייר
+subgroup-money = כסף
+subgroup-mail = דואר
+subgroup-writing = כתיבה
+subgroup-office = משרד
+subgroup-lock = מנעולים
+subgroup-tool = כלים
+subgroup-science = מדע
+subgroup-medical = רפואה
+subgroup-household = משק בית
+subgroup-other-object = חפצים אחרים
+subgroup-transport-sign = תמרורים
+subgroup-warning = אזהרות
+subgroup-arrow = חצים
+subgroup-religion = דת
+subgroup-zodiac = מזלות
+subgroup-av-symbol = שמע ווידאו
+subgroup-gender = מגדר
+subgroup-math = מתמטיקה
+subgroup-punctuation = פיסוק
+subgroup-currency = מטבעות
+subgroup-other-symbol = סמלים אחרים
+subgroup-keycap = מקשים
+subgroup-alphanum = אותיות ומספרים
+subgroup-geometric = צורות
+subgroup-flag = דגלים
+subgroup-country-flag = מדינות
+subgroup-subdivision-flag = אזורים
@@ -0,0 +1,121 @@
+search-placeholder = Cerca...
+searching = Ricerca in corso...
+nothing-found = Nessun risultato
+history-empty = Usa delle emoji perché compaiano qui
+results = { $total ->
+    [one] { $total } risultato
+   *[other] { $total } risultati
+}
+results-shown = { $shown } di { $total } risultati
+copy = Copia
+search-language = Lingua di ricerca
+
+category-history = Usate di recente
+category-smileys-and-emotion = Faccine ed emozioni
+category-people-and-body = Persone e corpo
+category-animals-and-nature = Animali e natura
+category-food-and-drink = Cibo e bevande
+category-travel-and-places = Viaggi e luoghi
+category-activities = Attività
+category-objects = Oggetti
+category-symbols = Simboli
+category-flags = Bandiere
+
+subgroup-face-smiling = Facce sorridenti
+subgroup-face-affection = Affetto
+subgroup-face-tongue = Linguacce
+subgroup-face-hand = Facce con mani
+subgroup-face-neutral-skeptical = Neutre e scettiche
+subgroup-face-sleepy = Sonno
+subgroup-face-unwell = Malessere
+subgroup-face-hat = Cappelli
+subgroup-face-glasses = Occhiali
+subgroup-face-concerned = Preoccupazione
+subgroup-face-negative = Negative
+subgroup-face-costume = Costumi
+subgroup-cat-face = Facce di gatto
+subgroup-monkey-face = Facce di scimmia
+subgroup-emotion = Emozioni
+subgroup-hand-fingers-open = Mani aperte
+subgroup-hand-fingers-partial = Segni con le dita
+subgroup-hand-single-finger = Indicare
+subgroup-hand-fingers-closed = Mani chiuse
+subgroup-hands = Mani
+subgroup-hand-prop = Mani con oggetti
+subgroup-body-parts = Parti del corpo
+subgroup-person = Persone
+subgroup-person-gesture = Gesti
+subgroup-person-role = Mestieri
+subgroup-person-fantasy = Fantasia
+subgroup-person-activity = Attività
+subgroup-person-sport = Sportivi
+subgroup-person-resting = Riposo
+subgroup-family = Famiglie
+subgroup-person-symbol = Sagome
+subgroup-animal-mammal = Mammiferi
+subgroup-animal-bird = Uccelli
+subgroup-animal-amphibian = Anfibi
+subgroup-animal-reptile = Rettili
+subgroup-animal-marine = Animali marini
+subgroup-animal-bug = Insetti
+subgroup-plant-flower = Fiori
+subgroup-plant-other = Piante
+subgroup-food-fruit = Frutta
+subgroup-food-vegetable = Verdura
+subgroup-food-prepared = Piatti
+subgroup-food-asian = Cucina asiatica
+subgroup-food-marine = Frutti di mare
+subgroup-food-sweet = Dolci
+subgroup-drink = Bevande
+subgroup-dishware = Stoviglie
+subgroup-place-map = Mappe
+subgroup-place-geographic = Paesaggi
+subgroup-place-building = Edifici
+subgroup-place-religious = Luoghi di culto
+subgroup-place-other = Altri luoghi
+subgroup-transport-ground = Trasporto terrestre
+subgroup-transport-water = Trasporto marittimo
+subgroup-transport-air = Trasporto aereo
+subgroup-hotel = Hotel
+subgroup-time = Tempo
+subgroup-sky-and-weather = Cielo e meteo
+subgroup-event = Eventi
+subgroup-award-medal = Premi
+subgroup-sport = Sport
+subgroup-game = Giochi
+subgroup-arts-and-crafts = Arte e artigianato
+subgroup-clothing = Abbigliamento
+subgroup-sound = Suono
+subgroup-music = Musica
+subgroup-musical-instrument = Strumenti
+subgroup-phone = Telefoni
+subgroup-computer = Computer
+subgroup-light-and-video = Luce e video
+subgroup-book-paper = Libri e carta
+subgroup-money = Denaro
+subgroup-mail = Posta
+subgroup-writing = Scrittura
+subgroup-office = Ufficio
+subgroup-lock = Lucchetti
+subgroup-tool = Attrezzi
+subgroup-science = Scienza
+subgroup-medical = Medicina
+subgroup-household = Casa
+subgroup-other-object = Altri oggetti
+subgroup-transport-sign = Segnaletica
+subgroup-warning = Avvisi
+subgroup-arrow = Frecce
+subgroup-religion = Religione
+subgroup-zodiac = Zodiaco
+subgroup-av-symbol = Audio e video
+subgroup-gender = Genere
+subgroup-math = Matematica
+subgroup-punctuation = Punteggiatura
+subgroup-currency = Valute
+subgroup-other-symbol = Altri simboli
+subgroup-keycap = Tasti
+subgroup-alphanum = Lettere e numeri
+subgroup-geometric = Forme
+subgroup-flag = Bandiere
+subgroup-country-flag = Paesi
+subgroup-subdivision-flag = Regioni
@@ -0,0 +1,118 @@
+search-placeholder = 検索...
+searching = 検索中...
+nothing-found = 見つかりませんでした
+history-empty = 使った絵文字がここに表示されます
+results = { $total } 件
+results-shown = { $total } 件中 { $shown } 件
+copy = コピー
+search-language = 検索言語
+
+category-history = 最近使ったもの
+category-smileys-and-emotion = スマイリーと感情
+category-people-and-body = 人と体
+category-animals-and-nature = 動物と自然
+category-food-and-drink = 食べ物と飲み物
+category-travel-and-places = 旅行と場所
+category-activities = アクティビティ
+category-objects = 物
+category-symbols = 記号
+category-flags = 旗
+
+subgroup-face-smiling = 笑顔
+subgroup-face-affection = 愛情
+subgroup-face-tongue = 舌を出した顔
+subgroup-face-hand = 手のある顔
+subgroup-face-neutral-skeptical = 無表情・懐疑
+subgroup-face-sleepy = 眠い
+subgroup-face-unwell = 体調不良
+subgroup-face-hat = 帽子
+subgroup-face-glasses = メガネ
+subgroup-face-concerned = 心配
+subgroup-face-negative = ネガティブ
+subgroup-face-costume = 仮装
+subgroup-cat-face = 猫の顔
+subgroup-monkey-face = 猿の顔
+subgroup-emotion = 感情
+subgroup-hand-fingers-open = 開いた手
+subgroup-hand-fingers-partial = 指のサイン
+subgroup-hand-single-finger = 指差し
+subgroup-hand-fingers-closed = 握った手
+subgroup-hands = 両手
+subgroup-hand-prop = 物を持つ手
+subgroup-body-parts = 体の部位
+subgroup-person = 人
+subgroup-person-gesture = ジェスチャー
+subgroup-person-role = 職業
+subgroup-person-fantasy = ファンタジー
+subgroup-person-activity = 活動
+subgroup-person-sport = スポーツ選手
+subgroup-person-resting = 休息
+subgroup-family = 家族
+subgroup-person-symbol = シルエット
+subgroup-animal-mammal = 哺乳類
+subgroup-animal-bird = 鳥
+subgroup-animal-amphibian = 両生類
+subgroup-animal-reptile = 爬虫類
+subgroup-animal-marine = 海の生き物
+subgroup-animal-bug = 虫
+subgroup-plant-flower = 花
+subgroup-plant-other = 植物
+subgroup-food-fruit = 果物
+subgroup-food-vegetable = 野菜
+subgroup-food-prepared = 料理
+subgroup-food-asian = アジア料理
+subgroup-food-marine = 魚介類
+subgroup-food-sweet = 甘いもの
+subgroup-drink = 飲み物
+subgroup-dishware = 食器
+subgroup-place-map = 地図
+subgroup-place-geographic = 地形
+subgroup-place-building = 建物
+subgroup-place-religious = 宗教施設
+subgroup-place-other = その他の場所
+subgroup-transport-ground = 陸の乗り物
+subgroup-transport-water = 水の乗り物
+subgroup-transport-air = 空の乗り物
+subgroup-hotel = ホテル
+subgroup-time = 時間
+subgroup-sky-and-weather = 空と天気
+subgroup-event = イベント
+subgroup-award-medal = 賞
+subgroup-sport = スポーツ
+subgroup-game = ゲーム
+subgroup-arts-and-crafts = 芸術と工芸
+subgroup-clothing = 服
+subgroup-sound = 音
+subgroup-music = 音楽
+subgroup-musical-instrument = 楽器
+subgroup-phone = 電話
+subgroup-computer = コンピューター
+subgroup-light-and-video = 光と映像
+subgroup-book-paper = 本と紙
+subgroup-money = お金
+subgroup-mail = 郵便
+subgroup-writing = 筆記
+subgroup-office = 事務
+subgroup-lock = 鍵
+subgroup-tool = 道具
+subgroup-science = 科学
+subgroup-medical = 医療
+subgroup-household = 家庭用品
+subgroup-other-object = その他の物
+subgroup-transport-sign = 交通標識
+subgroup-warning = 警告
+subgroup-arrow = 矢印
+subgroup-religion = 宗教
+subgroup-zodiac = 星座
+subgroup-av-symbol = AV記号
+subgroup-gender = 性別
+subgroup-math = 数学
+subgroup-punctuation = 句読点
+subgroup-currency = 通貨
+subgroup-other-symbol = その他の記号
+subgroup-keycap = キーキャップ
+subgroup-alphanum = 英数字
+subgroup-geometric = 図形
+subgroup-flag = 旗
+subgroup-country-flag = 国旗
+subgroup-subdivision-flag = 地域の旗
@@ -0,0 +1,118 @@
+search-placeholder = 검색...
+searching = 검색 중...
+nothing-found = 찾을 수 없습니다
+history-empty = 사용한 이모지가 여기에 표시됩니다
+results = 결과 { $total }개
+results-shown = 결과 { $total }개 중 { $shown }개
+copy = 복사
+search-language = 검색 언어
+
+category-history = 최근 사용
+category-smileys-and-emotion = 스마일리 및 감정
+category-people-and-body = 사람 및 신체
+category-animals-and-nature = 동물 및 자연
+category-food-and-drink = 음식 및 음료
+category-travel-and-places = 여행 및 장소
+category-activities = 활동
+category-objects = 사물
+category-symbols = 기호
+category-flags = 깃발
+
+subgroup-face-smiling = 웃는 얼굴
+subgroup-face-affection = 애정
+subgroup-face-tongue = 혀 내민 얼굴
+subgroup-face-hand = 손이 있는 얼굴
+subgroup-face-neutral-skeptical = 무표정·회의
+subgroup-face-sleepy = 졸림
+subgroup-face-unwell = 아픔
+subgroup-face-hat = 모자
+subgroup-face-glasses = 안경
+subgroup-face-concerned = 걱정
+subgroup-face-negative = 부정적
+subgroup-face-costume = 분장
+subgroup-cat-face = 고양이 얼굴
+subgroup-monkey-face = 원숭이 얼굴
+subgroup-emotion = 감정
+subgroup-hand-fingers-open = 편 손
+subgroup-hand-fingers-partial = 손가락 신호
+subgroup-hand-single-finger = 가리키기
+subgroup-hand-fingers-closed = 쥔 손
+subgroup-hands = 양손
+subgroup-hand-prop = 물건을 든 손
+subgroup-body-parts = 신체 부위
+subgroup-person = 사람
+subgroup-person-gesture = 몸짓
+subgroup-person-role = 직업
+subgroup-person-fantasy = 판타지
+subgroup-person-activity = 활동
+subgroup-person-sport = 운동선수
+subgroup-person-resting = 휴식
+subgroup-family = 가족
+subgroup-person-symbol = 실루엣
+subgroup-animal-mammal = 포유류
+subgroup-animal-bird = 조류
+subgroup-animal-amphibian = 양서류
+subgroup-animal-reptile = 파충류
+subgroup-animal-marine = 바다 생물
+subgroup-animal-bug = 벌레
+subgroup-plant-flower = 꽃
+subgroup-plant-other = 식물
+subgroup-food-fruit = 과일
+subgroup-food-vegetable = 채소
+subgroup-food-prepared = 요리
+subgroup-food-asian = 아시아 음식
+subgroup-food-marine = 해산물
+subgroup-food-sweet = 단 음식
+subgroup-drink = 음료
+subgroup-dishware = 식기
+subgroup-place-map = 지도
+subgroup-place-geographic = 지형
+subgroup-place-building = 건물
+subgroup-place-religious = 종교 시설
+subgroup-place-other = 기타 장소
+subgroup-transport-ground = 육상 교통
+subgroup-transport-water = 수상 교통
+subgroup-transport-air = 항공 교통
+subgroup-hotel = 호텔
+subgroup-time = 시간
+subgroup-sky-and-weather = 하늘과 날씨
+subgroup-event = 행사
+subgroup-award-medal = 상
+subgroup-sport = 스포츠
+subgroup-game = 게임
+subgroup-arts-and-crafts = 예술과 공예
+subgroup-clothing = 의류
+subgroup-sound = 소리
+subgroup-music = 음악
+subgroup-musical-instrument = 악기
+subgroup-phone = 전화
+subgroup-computer = 컴퓨터
+subgroup-light-and-video = 조명과 영상
+subgroup-book-paper = 책과 종이
+subgroup-money = 돈
+subgroup-mail = 우편
+subgroup-writing = 필기
+subgroup-office = 사무
+subgroup-lock = 자물쇠
+subgroup-tool = 도구
+subgroup-science = 과학
+subgroup-medical = 의료
+subgroup-household = 생활용품
+subgroup-other-object = 기타 물건
+subgroup-transport-sign = 교통 표지
+subgroup-warning = 경고
+subgroup-arrow = 화살표
+subgroup-religion = 종교
+subgroup-zodiac = 별자리
+subgroup-av-symbol = AV 기호
+subgroup-gender = 성별
+subgroup-math = 수학
+subgroup-punctuation = 문장 부호
+subgroup-currency = 통화
+subgroup-other-symbol = 기타 기호
+subgroup-keycap = 키캡
+subgroup-alphanum = 영숫자
+subgroup-geometric = 도형
+subgroup-flag = 깃발
+subgroup-country-flag = 국기
+subgroup-subdivision-flag = 지역 깃발
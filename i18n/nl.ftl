@@ -0,0 +1,121 @@
+search-placeholder = Zoeken...
+searching = Bezig met zoeken...
+nothing-found = Niets gevonden
+history-empty = Gebruik emoji’s om ze hier te laten verschijnen
+results = { $total ->
+    [one] { $total } resultaat
+   *[other] { $total } resultaten
+}
+results-shown = { $shown } van { $total } resultaten
+copy = Kopiëren
+search-language = Zoektaal
+
+category-history = Recent gebruikt
+category-smileys-and-emotion = Smileys en emoties
+category-people-and-body = Mensen en lichaam
+category-animals-and-nature = Dieren en natuur
+category-food-and-drink = Eten en drinken
+category-travel-and-places = Reizen en plaatsen
+category-activities = Activiteiten
+category-objects = Voorwerpen
+category-symbols = Symbolen
+category-flags = Vlaggen
+
+subgroup-face-smiling = Lachende gezichten
+subgroup-face-affection = Genegenheid
+subgroup-face-tongue = Tong uit
+subgroup-face-hand = Gezichten met handen
+subgroup-face-neutral-skeptical = Neutraal & sceptisch
+subgroup-face-sleepy = Slaperig
+subgroup-face-unwell = Onwel
+subgroup-face-hat = Hoeden
+subgroup-face-glasses = Brillen
+subgroup-face-concerned = Bezorgd
+subgroup-face-negative = Negatief
+subgroup-face-costume = Verkleed
+subgroup-cat-face = Kattengezichten
+subgroup-monkey-face = Apengezichten
+subgroup-emotion = Emoties
+subgroup-hand-fingers-open = Open handen
+subgroup-hand-fingers-partial = Vingertekens
+subgroup-hand-single-finger = Wijzen
+subgroup-hand-fingers-closed = Gesloten handen
+subgroup-hands = Handen
+subgroup-hand-prop = Handen met voorwerpen
+subgroup-body-parts = Lichaamsdelen
+subgroup-person = Personen
+subgroup-person-gesture = Gebaren
+subgroup-person-role = Beroepen
+subgroup-person-fantasy = Fantasie
+subgroup-person-activity = Bezigheden
+subgroup-person-sport = Sporters
+subgroup-person-resting = Rusten
+subgroup-family = Gezinnen
+subgroup-person-symbol = Silhouetten
+subgroup-animal-mammal = Zoogdieren
+subgroup-animal-bird = Vogels
+subgroup-animal-amphibian = Amfibieën
+subgroup-animal-reptile = Reptielen
+subgroup-animal-marine = Zeedieren
+subgroup-animal-bug = Insecten
+subgroup-plant-flower = Bloemen
+subgroup-plant-other = Planten
+subgroup-food-fruit = Fruit
+subgroup-food-vegetable = Groenten
+subgroup-food-prepared = Gerechten
+subgroup-food-asian = Aziatisch eten
+subgroup-food-marine = Zeevruchten
+subgroup-food-sweet = Zoetigheid
+subgroup-drink = Dranken
+subgroup-dishware = Servies
+subgroup-place-map = Kaarten
+subgroup-place-geographic = Landschappen
+subgroup-place-building = Gebouwen
+subgroup-place-religious = Gebedshuizen
+subgroup-place-other = Andere plaatsen
+subgroup-transport-ground = Vervoer over land
+subgroup-transport-water = Vervoer over water
+subgroup-transport-air = Luchtvervoer
+subgroup-hotel = Hotel
+subgroup-time = Tijd
+subgroup-sky-and-weather = Lucht & weer
+subgroup-event = Evenementen
+subgroup-award-medal = Prijzen
+subgroup-sport = Sport
+subgroup-game = Spellen
+subgroup-arts-and-crafts = Kunst & handwerk
+subgroup-clothing = Kleding
+subgroup-sound = Geluid
+subgroup-music = Muziek
+subgroup-musical-instrument = Instrumenten
+subgroup-phone = Telefoons
+subgroup-computer = Computers
+subgroup-light-and-video = Licht & video
+subgroup-book-paper = Boeken & papier
+subgroup-money = Geld
+subgroup-mail = Post
+subgroup-writing = Schrijven
+subgroup-office = Kantoor
+subgroup-lock = Sloten
+subgroup-tool = Gereedschap
+subgroup-science = Wetenschap
+subgroup-medical = Medisch
+subgroup-household = Huishouden
+subgroup-other-object = Andere voorwerpen
+subgroup-transport-sign = Verkeersborden
+subgroup-warning = Waarschuwingen
+subgroup-arrow = Pijlen
+subgroup-religion = Religie
+subgroup-zodiac = Dierenriem
+subgroup-av-symbol = Audio & video
+subgroup-gender = Gender
+subgroup-math = Wiskunde
+subgroup-punctuation = Leestekens
+subgroup-currency = Valuta
+subgroup-other-symbol = Andere symbolen
+subgroup-keycap = Toetsen
+subgroup-alphanum = Letters & cijfers
+subgroup-geometric = Vormen
+subgroup-flag = Vlaggen
+subgroup-country-flag = Landen
+subgroup-subdivision-flag = Regio's
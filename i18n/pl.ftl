@@ -0,0 +1,122 @@
+search-placeholder = Szukaj...
+searching = Wyszukiwanie...
+nothing-found = Nic nie znaleziono
+history-empty = Używane emoji pojawią się tutaj
+results = { $total ->
+    [one] { $total } wynik
+    [few] { $total } wyniki
+   *[other] { $total } wyników
+}
+results-shown = { $shown } z { $total } wyników
+copy = Kopiuj
+search-language = Język wyszukiwania
+
+category-history = Ostatnio używane
+category-smileys-and-emotion = Buźki i emocje
+category-people-and-body = Ludzie i ciało
+category-animals-and-nature = Zwierzęta i przyroda
+category-food-and-drink = Jedzenie i picie
+category-travel-and-places = Podróże i miejsca
+category-activities = Aktywności
+category-objects = Przedmioty
+category-symbols = Symbole
+category-flags = Flagi
+
+subgroup-face-smiling = Uśmiechnięte twarze
+subgroup-face-affection = Czułość
+subgroup-face-tongue = Wystawiony język
+subgroup-face-hand = Twarze z dłońmi
+subgroup-face-neutral-skeptical = Neutralne i sceptyczne
+subgroup-face-sleepy = Senność
+subgroup-face-unwell = Złe samopoczucie
+subgroup-face-hat = Kapelusze
+subgroup-face-glasses = Okulary
+subgroup-face-concerned = Zmartwienie
+subgroup-face-negative = Negatywne
+subgroup-face-costume = Przebrania
+subgroup-cat-face = Kocie pyszczki
+subgroup-monkey-face = Małpie pyszczki
+subgroup-emotion = Emocje
+subgroup-hand-fingers-open = Otwarte dłonie
+subgroup-hand-fingers-partial = Znaki palcami
+subgroup-hand-single-finger = Wskazywanie
+subgroup-hand-fingers-closed = Zamknięte dłonie
+subgroup-hands = Dłonie
+subgroup-hand-prop = Dłonie z przedmiotami
+subgroup-body-parts = Części ciała
+subgroup-person = Osoby
+subgroup-person-gesture = Gesty
+subgroup-person-role = Zawody
+subgroup-person-fantasy = Fantastyka
+subgroup-person-activity = Zajęcia
+subgroup-person-sport = Sportowcy
+subgroup-person-resting = Odpoczynek
+subgroup-family = Rodziny
+subgroup-person-symbol = Sylwetki
+subgroup-animal-mammal = Ssaki
+subgroup-animal-bird = Ptaki
+subgroup-animal-amphibian = Płazy
+subgroup-animal-reptile = Gady
+subgroup-animal-marine = Zwierzęta morskie
+subgroup-animal-bug = Owady
+subgroup-plant-flower = Kwiaty
+subgroup-plant-other = Rośliny
+subgroup-food-fruit = Owoce
+subgroup-food-vegetable = Warzywa
+subgroup-food-prepared = Dania
+subgroup-food-asian = Kuchnia azjatycka
+subgroup-food-marine = Owoce morza
+subgroup-food-sweet = Słodycze
+subgroup-drink = Napoje
+subgroup-dishware = Naczynia
+subgroup-place-map = Mapy
+subgroup-place-geographic = Krajobrazy
+subgroup-place-building = Budynki
+subgroup-place-religious = Miejsca kultu
+subgroup-place-other = Inne miejsca
+subgroup-transport-ground = Transport lądowy
+subgroup-transport-water = Transport wodny
+subgroup-transport-air = Transport lotniczy
+subgroup-hotel = Hotel
+subgroup-time = Czas
+subgroup-sky-and-weather = Niebo i pogoda
+subgroup-event = Wydarzenia
+subgroup-award-medal = Nagrody
+subgroup-sport = Sport
+subgroup-game = Gry
+subgroup-arts-and-crafts = Sztuka i rękodzieło
+subgroup-clothing = Ubrania
+subgroup-sound = Dźwięk
+subgroup-music = Muzyka
+subgroup-musical-instrument = Instrumenty
+subgroup-phone = Telefony
+subgroup-computer = Komputery
+subgroup-light-and-video = Światło i wideo
+subgroup-book-paper = Książki i papier
+subgroup-money = Pieniądze
+subgroup-mail = Poczta
+subgroup-writing = Pisanie
+subgroup-office = Biuro
+subgroup-lock = Kłódki
+subgroup-tool = Narzędzia
+subgroup-science = Nauka
+subgroup-medical = Medycyna
+subgroup-household = Dom
+subgroup-other-object = Inne przedmioty
+subgroup-transport-sign = Znaki drogowe
+subgroup-warning = Ostrzeżenia
+subgroup-arrow = Strzałki
+subgroup-religion = Religia
+subgroup-zodiac = Zodiak
+subgroup-av-symbol = Audio i wideo
+subgroup-gender = Płeć
+subgroup-math = Matematyka
+subgroup-punctuation = Interpunkcja
+subgroup-currency = Waluty
+subgroup-other-symbol = Inne symbole
+subgroup-keycap = Klawisze
+subgroup-alphanum = Litery i cyfry
+subgroup-geometric = Kształty
+subgroup-flag = Flagi
+subgroup-country-flag = Kraje
+subgroup-subdivision-flag = Regiony
@@ -0,0 +1,121 @@
+search-placeholder = Pesquisar...
+searching = Pesquisando...
+nothing-found = Nada encontrado
+history-empty = Use emojis para que apareçam aqui
+results = { $total ->
+    [one] { $total } resultado
+   *[other] { $total } resultados
+}
+results-shown = { $shown } de { $total } resultados
+copy = Copiar
+search-language = Idioma da pesquisa
+
+category-history = Usados recentemente
+category-smileys-and-emotion = Carinhas e emoções
+category-people-and-body = Pessoas e corpo
+category-animals-and-nature = Animais e natureza
+category-food-and-drink = Comida e bebida
+category-travel-and-places = Viagens e lugares
+category-activities = Atividades
+category-objects = Objetos
+category-symbols = Símbolos
+category-flags = Bandeiras
+
+subgroup-face-smiling = Rostos sorridentes
+subgroup-face-affection = Afeto
+subgroup-face-tongue = Língua de fora
+subgroup-face-hand = Rostos com mãos
+subgroup-face-neutral-skeptical = Neutros e céticos
+subgroup-face-sleepy = Sono
+subgroup-face-unwell = Mal-estar
+subgroup-face-hat = Chapéus
+subgroup-face-glasses = Óculos
+subgroup-face-concerned = Preocupação
+subgroup-face-negative = Negativos
+subgroup-face-costume = Fantasias
+subgroup-cat-face = Rostos de gato
+subgroup-monkey-face = Rostos de macaco
+subgroup-emotion = Emoções
+subgroup-hand-fingers-open = Mãos abertas
+subgroup-hand-fingers-partial = Sinais com os dedos
+subgroup-hand-single-finger = Apontar
+subgroup-hand-fingers-closed = Mãos fechadas
+subgroup-hands = Mãos
+subgroup-hand-prop = Mãos com objetos
+subgroup-body-parts = Partes do corpo
+subgroup-person = Pessoas
+subgroup-person-gesture = Gestos
+subgroup-person-role = Profissões
+subgroup-person-fantasy = Fantasia
+subgroup-person-activity = Atividades
+subgroup-person-sport = Atletas
+subgroup-person-resting = Descanso
+subgroup-family = Famílias
+subgroup-person-symbol = Silhuetas
+subgroup-animal-mammal = Mamíferos
+subgroup-animal-bird = Aves
+subgroup-animal-amphibian = Anfíbios
+subgroup-animal-reptile = Répteis
+subgroup-animal-marine = Animais marinhos
+subgroup-animal-bug = Insetos
+subgroup-plant-flower = Flores
+subgroup-plant-other = Plantas
+subgroup-food-fruit = Frutas
+subgroup-food-vegetable = Legumes
+subgroup-food-prepared = Pratos
+subgroup-food-asian = Comida asiática
+subgroup-food-marine = Frutos do mar
+subgroup-food-sweet = Doces
+subgroup-drink = Bebidas
+subgroup-dishware = Louça
+subgroup-place-map = Mapas
+subgroup-place-geographic = Paisagens
+subgroup-place-building = Edifícios
+subgroup-place-religious = Locais de culto
+subgroup-place-other = Outros lugares
+subgroup-transport-ground = Transporte terrestre
+subgroup-transport-water = Transporte aquático
+subgroup-transport-air = Transporte aéreo
+subgroup-hotel = Hotel
+subgroup-time = Tempo
+subgroup-sky-and-weather = Céu e clima
+subgroup-event = Eventos
+subgroup-award-medal = Troféus
+subgroup-sport = Esportes
+subgroup-game = Jogos
+subgroup-arts-and-crafts = Artes e artesanato
+subgroup-clothing = Roupas
+subgroup-sound = Som
+subgroup-music = Música
+subgroup-musical-instrument = Instrumentos
+subgroup-phone = Telefones
+subgroup-computer = Computadores
+subgroup-light-and-video = Luz e vídeo
+subgroup-book-paper = Livros e papel
+subgroup-money = Dinheiro
+subgroup-mail = Correio
+subgroup-writing = Escrita
+subgroup-office = Escritório
+subgroup-lock = Cadeados
+subgroup-tool = Ferramentas
+subgroup-science = Ciência
+subgroup-medical = Medicina
+subgroup-household = Casa
+subgroup-other-object = Outros objetos
+subgroup-transport-sign = Sinais de trânsito
+subgroup-warning = Avisos
+subgroup-arrow = Setas
+subgroup-religion = Religião
+subgroup-zodiac = Zodíaco
+subgroup-av-symbol = Áudio e vídeo
+subgroup-gender = Gênero
+subgroup-math = Matemática
+subgroup-punctuation = Pontuação
+subgroup-currency = Moedas
+subgroup-other-symbol = Outros símbolos
+subgroup-keycap = Teclas
+subgroup-alphanum = Letras e números
+subgroup-geometric = Formas
+subgroup-flag = Bandeiras
+subgroup-country-flag = Países
+subgroup-subdivision-flag = Regiões
@@ -0,0 +1,122 @@
+search-placeholder = Поиск...
+searching = Идёт поиск...
+nothing-found = Ничего не найдено
+history-empty = Здесь появятся использованные эмодзи
+results = { $total ->
+    [one] { $total } результат
+    [few] { $total } результата
+   *[other] { $total } результатов
+}
+results-shown = { $shown } из { $total }
+copy = Копировать
+search-language = Язык поиска
+
+category-history = Недавние
+category-smileys-and-emotion = Смайлики и эмоции
+category-people-and-body = Люди и тело
+category-animals-and-nature = Животные и природа
+category-food-and-drink = Еда и напитки
+category-travel-and-places = Путешествия и места
+category-activities = Занятия
+category-objects = Предметы
+category-symbols = Символы
+category-flags = Флаги
+
+subgroup-face-smiling = Улыбающиеся лица
+subgroup-face-affection = Нежность
+subgroup-face-tongue = Язык
+subgroup-face-hand = Лица с руками
+subgroup-face-neutral-skeptical = Нейтральные и скептические
+subgroup-face-sleepy = Сонливость
+subgroup-face-unwell = Недомогание
+subgroup-face-hat = Шляпы
+subgroup-face-glasses = Очки
+subgroup-face-concerned = Беспокойство
+subgroup-face-negative = Негативные
+subgroup-face-costume = Костюмы
+subgroup-cat-face = Кошачьи мордочки
+subgroup-monkey-face = Обезьяньи мордочки
+subgroup-emotion = Эмоции
+subgroup-hand-fingers-open = Открытые ладони
+subgroup-hand-fingers-partial = Жесты пальцами
+subgroup-hand-single-finger = Указательный палец
+subgroup-hand-fingers-closed = Сжатые руки
+subgroup-hands = Руки
+subgroup-hand-prop = Руки с предметами
+subgroup-body-parts = Части тела
+subgroup-person = Люди
+subgroup-person-gesture = Жесты
+subgroup-person-role = Профессии
+subgroup-person-fantasy = Фэнтези
+subgroup-person-activity = Занятия
+subgroup-person-sport = Спортсмены
+subgroup-person-resting = Отдых
+subgroup-family = Семьи
+subgroup-person-symbol = Силуэты
+subgroup-animal-mammal = Млекопитающие
+subgroup-animal-bird = Птицы
+subgroup-animal-amphibian = Земноводные
+subgroup-animal-reptile = Рептилии
+subgroup-animal-marine = Морские животные
+subgroup-animal-bug = Насекомые
+subgroup-plant-flower = Цветы
+subgroup-plant-other = Растения
+subgroup-food-fruit = Фрукты
+subgroup-food-vegetable = Овощи
+subgroup-food-prepared = Блюда
+subgroup-food-asian = Азиатская кухня
+subgroup-food-marine = Морепродукты
+subgroup-food-sweet = Сладости
+subgroup-drink = Напитки
+subgroup-dishware = Посуда
+subgroup-place-map = Карты
+subgroup-place-geographic = Пейзажи
+subgroup-place-building = Здания
+subgroup-place-religious = Места поклонения
+subgroup-place-other = Другие места
+subgroup-transport-ground = Наземный транспорт
+subgroup-transport-water = Водный транспорт
+subgroup-transport-air = Воздушный транспорт
+subgroup-hotel = Гостиница
+subgroup-time = Время
+subgroup-sky-and-weather = Небо и погода
+subgroup-event = События
+subgroup-award-medal = Награды
+subgroup-sport = Спорт
+subgroup-game = Игры
+subgroup-arts-and-crafts = Искусство и ремёсла
+subgroup-clothing = Одежда
+subgroup-sound = Звук
+subgroup-music = Музыка
+subgroup-musical-instrument = Музыкальные инструменты
+subgroup-phone = Телефоны
+subgroup-computer = Компьютеры
+subgroup-light-and-video = Свет и видео
+subgroup-book-paper = Книги и бумага
+subgroup-money = Деньги
+subgroup-mail = Почта
+subgroup-writing = Письмо
+subgroup-office = Офис
+subgroup-lock = Замки
+subgroup-tool = Инструменты
+subgroup-science = Наука
+subgroup-medical = Медицина
+subgroup-household = Быт
+subgroup-other-object = Другие предметы
+subgroup-transport-sign = Дорожные знаки
+subgroup-warning = Предупреждения
+subgroup-arrow = Стрелки
+subgroup-religion = Религия
+subgroup-zodiac = Зодиак
+subgroup-av-symbol = Аудио и видео
+subgroup-gender = Пол
+subgroup-math = Математика
+subgroup-punctuation = Пунктуация
+subgroup-currency = Валюты
+subgroup-other-symbol = Другие символы
+subgroup-keycap = Клавиши
+subgroup-alphanum = Буквы и цифры
+subgroup-geometric = Фигуры
+subgroup-flag = Флаги
+subgroup-country-flag = Страны
+subgroup-subdivision-flag = Регионы
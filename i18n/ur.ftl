@@ -0,0 +1,121 @@
+search-placeholder = تلاش کریں...
+searching = تلاش جاری ہے...
+nothing-found = کچھ نہیں ملا
+history-empty = استعمال شدہ ایموجی یہاں نظر آئیں گے
+results = { $total ->
+    [one] { $total } نتیجہ
+   *[other] { $total } نتائج
+}
+results-shown = { $total } میں سے { $shown } نتائج
+copy = کاپی کریں
+search-language = تلاش کی زبان
+
+category-history = حال ہی میں استعمال شدہ
+category-smileys-and-emotion = اسمائلیز اور جذبات
+category-people-and-body = لوگ اور جسم
+category-animals-and-nature = جانور اور فطرت
+category-food-and-drink = کھانا اور مشروبات
+category-travel-and-places = سفر اور مقامات
+category-activities = سرگرمیاں
+category-objects = اشیاء
+category-symbols = علامات
+category-flags = جھنڈے
+
+subgroup-face-smiling = مسکراتے چہرے
+subgroup-face-affection = محبت
+subgroup-face-tongue = زبان باہر
+subgroup-face-hand = ہاتھوں والے چہرے
+subgroup-face-neutral-skeptical = غیر جانبدار اور مشکوک
+subgroup-face-sleepy = نیند
+subgroup-face-unwell = بیماری
+subgroup-face-hat = ٹوپیاں
+subgroup-face-glasses = عینکیں
+subgroup-face-concerned = فکرمند
+subgroup-face-negative = منفی
+subgroup-face-costume = بھیس
+subgroup-cat-face = بلی کے چہرے
+subgroup-monkey-face = بندر کے چہرے
+subgroup-emotion = جذبات
+subgroup-hand-fingers-open = کھلے ہاتھ
+subgroup-hand-fingers-partial = انگلیوں کے اشارے
+subgroup-hand-single-finger = اشارہ کرنا
+subgroup-hand-fingers-closed = بند ہاتھ
+subgroup-hands = دونوں ہاتھ
+subgroup-hand-prop = چیزیں تھامے ہاتھ
+subgroup-body-parts = جسم کے حصے
+subgroup-person = لوگ
+subgroup-person-gesture = اشارے
+subgroup-person-role = پیشے
+subgroup-person-fantasy = خیالی
+subgroup-person-activity = سرگرمیاں
+subgroup-person-sport = کھلاڑی
+subgroup-person-resting = آرام
+subgroup-family = خاندان
+subgroup-person-symbol = سائے
+subgroup-animal-mammal = ممالیہ
+subgroup-animal-bird = پرندے
+subgroup-animal-amphibian = جل تھلیے
+subgroup-animal-reptile = رینگنے والے جانور
+subgroup-animal-marine = سمندری جانور
+subgroup-animal-bug = کیڑے
+subgroup-plant-flower = پھول
+subgroup-plant-other = پودے
+subgroup-food-fruit = پھل
+subgroup-food-vegetable = سبزیاں
+subgroup-food-prepared = پکوان
+subgroup-food-asian = ایشیائی کھانا
+subgroup-food-marine = سمندری غذا
+subgroup-food-sweet = مٹھائیاں
+subgroup-drink = مشروبات
+subgroup-dishware = برتن
+subgroup-place-map = نقشے
+subgroup-place-geographic = مناظر
+subgroup-place-building = عمارتیں
+subgroup-place-religious = عبادت گاہیں
+subgroup-place-other = دیگر مقامات
+subgroup-transport-ground = زمینی نقل و حمل
+subgroup-transport-water = آبی نقل و حمل
+subgroup-transport-air = فضائی نقل و حمل
+subgroup-hotel = ہوٹل
+subgroup-time = وقت
+subgroup-sky-and-weather = آسمان اور موسم
+subgroup-event = تقریبات
+subgroup-award-medal = انعامات
+subgroup-sport = کھیل
+subgroup-game = گیمز
+subgroup-arts-and-crafts = فنون اور دستکاری
+subgroup-clothing = لباس
+subgroup-sound = آواز
+subgroup-music = موسیقی
+subgroup-musical-instrument = ساز
+subgroup-phone = فون
+subgroup-computer = کمپیوٹر
+subgroup-light-and-video = روشنی اور ویڈیو
+subgroup-book-paper = کتابیں اور کاغذ
+subgroup-money = پیسہ
+subgroup-mail = ڈاک
+subgroup-writing = لکھائی
+subgroup-office = دفتر
+subgroup-lock = تالے
+subgroup-tool = اوزار
+subgroup-science = سائنس
+subgroup-medical = طبی
+subgroup-household = گھریلو
+subgroup-other-object = دیگر اشیاء
+subgroup-transport-sign = ٹریفک کے نشان
+subgroup-warning = انتباہات
+subgroup-arrow = تیر
+subgroup-religion = مذہب
+subgroup-zodiac = برج
+subgroup-av-symbol = آڈیو اور ویڈیو
+subgroup-gender = جنس
+subgroup-math = ریاضی
+subgroup-punctuation = رموزِ اوقاف
+subgroup-currency = کرنسی
+subgroup-other-symbol = دیگر علامات
+subgroup-keycap = کلیدیں
+subgroup-alphanum = حروف اور اعداد
+subgroup-geometric = اشکال
+subgroup-flag = جھنڈے
+subgroup-country-flag = ممالک
+subgroup-subdivision-flag = علاقے
@@ -0,0 +1,118 @@
+search-placeholder = 搜索...
+searching = 正在搜索...
+nothing-found = 未找到任何结果
+history-empty = 使用过的表情会显示在这里
+results = { $total } 个结果
+results-shown = { $total } 个结果中的 { $shown } 个
+copy = 复制
+search-language = 搜索语言
+
+category-history = 最近使用
+category-smileys-and-emotion = 笑脸和情感
+category-people-and-body = 人物和身体
+category-animals-and-nature = 动物和自然
+category-food-and-drink = 食物和饮料
+category-travel-and-places = 旅行和地点
+category-activities = 活动
+category-objects = 物品
+category-symbols = 符号
+category-flags = 旗帜
+
+subgroup-face-smiling = 笑脸
+subgroup-face-affection = 爱意
+subgroup-face-tongue = 吐舌
+subgroup-face-hand = 带手的脸
+subgroup-face-neutral-skeptical = 中性与怀疑
+subgroup-face-sleepy = 困倦
+subgroup-face-unwell = 不适
+subgroup-face-hat = 帽子
+subgroup-face-glasses = 眼镜
+subgroup-face-concerned = 担忧
+subgroup-face-negative = 消极
+subgroup-face-costume = 装扮
+subgroup-cat-face = 猫脸
+subgroup-monkey-face = 猴脸
+subgroup-emotion = 情感
+subgroup-hand-fingers-open = 张开的手
+subgroup-hand-fingers-partial = 手指手势
+subgroup-hand-single-finger = 单指
+subgroup-hand-fingers-closed = 握拳
+subgroup-hands = 双手
+subgroup-hand-prop = 拿东西的手
+subgroup-body-parts = 身体部位
+subgroup-person = 人
+subgroup-person-gesture = 手势
+subgroup-person-role = 职业
+subgroup-person-fantasy = 奇幻
+subgroup-person-activity = 活动
+subgroup-person-sport = 运动员
+subgroup-person-resting = 休息
+subgroup-family = 家庭
+subgroup-person-symbol = 剪影
+subgroup-animal-mammal = 哺乳动物
+subgroup-animal-bird = 鸟类
+subgroup-animal-amphibian = 两栖动物
+subgroup-animal-reptile = 爬行动物
+subgroup-animal-marine = 海洋生物
+subgroup-animal-bug = 虫子
+subgroup-plant-flower = 花
+subgroup-plant-other = 植物
+subgroup-food-fruit = 水果
+subgroup-food-vegetable = 蔬菜
+subgroup-food-prepared = 菜肴
+subgroup-food-asian = 亚洲食物
+subgroup-food-marine = 海鲜
+subgroup-food-sweet = 甜食
+subgroup-drink = 饮品
+subgroup-dishware = 餐具
+subgroup-place-map = 地图
+subgroup-place-geographic = 地貌
+subgroup-place-building = 建筑
+subgroup-place-religious = 宗教场所
+subgroup-place-other = 其他地点
+subgroup-transport-ground = 陆路交通
+subgroup-transport-water = 水路交通
+subgroup-transport-air = 航空交通
+subgroup-hotel = 酒店
+subgroup-time = 时间
+subgroup-sky-and-weather = 天空与天气
+subgroup-event = 节庆
+subgroup-award-medal = 奖牌
+subgroup-sport = 运动
+subgroup-game = 游戏
+subgroup-arts-and-crafts = 艺术与手工
+subgroup-clothing = 服装
+subgroup-sound = 声音
+subgroup-music = 音乐
+subgroup-musical-instrument = 乐器
+subgroup-phone = 电话
+subgroup-computer = 电脑
+subgroup-light-and-video = 灯光与影像
+subgroup-book-paper = 书本与纸张
+subgroup-money = 钱
+subgroup-mail = 邮件
+subgroup-writing = 书写
+subgroup-office = 办公
+subgroup-lock = 锁
+subgroup-tool = 工具
+subgroup-science = 科学
+subgroup-medical = 医疗
+subgroup-household = 家居
+subgroup-other-object = 其他物品
+subgroup-transport-sign = 交通标志
+subgroup-warning = 警告
+subgroup-arrow = 箭头
+subgroup-religion = 宗教
+subgroup-zodiac = 星座
+subgroup-av-symbol = 影音符号
+subgroup-gender = 性别
+subgroup-math = 数学
+subgroup-punctuation = 标点
+subgroup-currency = 货币
+subgroup-other-symbol = 其他符号
+subgroup-keycap = 按键
+subgroup-alphanum = 字母数字
+subgroup-geometric = 几何图形
+subgroup-flag = 旗帜
+subgroup-country-flag = 国旗
+subgroup-subdivision-flag = 地区旗帜
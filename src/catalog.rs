@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use emoji::{lookup_by_glyph::lookup, Emoji};
+
+pub use crate::groups::subgroups;
+use crate::variants;
+
+// Generated by build.rs: the glyphs of every group, already filtered and sorted
+mod generated {
+    include!(concat!(env!("OUT_DIR"), "/catalog.rs"));
+}
+
+// Which forms are reached through others is only known once the variants are
+// worked out, those are left out here
+static GROUPS: LazyLock<HashMap<&'static str, Vec<&'static Emoji>>> = LazyLock::new(|| {
+    generated::GROUPS
+        .iter()
+        .map(|(group, glyphs)| {
+            let emojis = glyphs
+                .iter()
+                .filter_map(|g| lookup(g))
+                .filter(|e| !variants::is_toned(e) && !variants::is_grouped_out(e))
+                .collect();
+            (*group, emojis)
+        })
+        .collect()
+});
+
+/// Emojis shown in the grid for a group, sorted by subgroup and codepoint.
+/// Toned forms and other genders are left out, they are reached through
+/// their variants
+pub fn emojis_of(group: &str) -> &'static [&'static Emoji] {
+    GROUPS.get(group).map_or(&[], |emojis| emojis)
+}
@@ -0,0 +1,105 @@
+use emoji::{lookup_by_glyph::lookup, Emoji};
+
+// Classic ASCII emoticons and the emoji each one stands for
+const EMOTICONS: &[(&str, &str)] = &[
+    (":)", "🙂"),
+    (":-)", "🙂"),
+    ("=)", "🙂"),
+    ("(:", "🙂"),
+    (":D", "😃"),
+    (":-D", "😃"),
+    ("=D", "😃"),
+    ("xD", "😆"),
+    ("XD", "😆"),
+    (":(", "🙁"),
+    (":-(", "🙁"),
+    ("=(", "🙁"),
+    (":'(", "😢"),
+    (":'-(", "😢"),
+    ("T_T", "😭"),
+    (";)", "😉"),
+    (";-)", "😉"),
+    (":P", "😛"),
+    (":-P", "😛"),
+    (":p", "😛"),
+    (":-p", "😛"),
+    (";P", "😜"),
+    (";p", "😜"),
+    (":O", "😮"),
+    (":-O", "😮"),
+    (":o", "😮"),
+    (":-o", "😮"),
+    (":|", "😐"),
+    (":-|", "😐"),
+    ("-_-", "😑"),
+    (":/", "😕"),
+    (":-/", "😕"),
+    (":\\", "😕"),
+    (":S", "😖"),
+    (":s", "😖"),
+    (":*", "😘"),
+    (":-*", "😘"),
+    (":$", "😳"),
+    (":X", "🤐"),
+    (":x", "🤐"),
+    ("B)", "😎"),
+    ("B-)", "😎"),
+    ("8)", "😎"),
+    ("^_^", "😊"),
+    ("^^", "😊"),
+    (":3", "😺"),
+    ("D:", "😧"),
+    ("O:)", "😇"),
+    ("0:)", "😇"),
+    (">:)", "😈"),
+    (">:(", "😠"),
+    (":@", "😡"),
+    ("<3", "\u{2764}\u{fe0f}"),
+    ("</3", "💔"),
+    ("o/", "👋"),
+    ("\\o", "👋"),
+    ("\\o/", "🙌"),
+    ("¯\\_(ツ)_/¯", "🤷"),
+];
+
+/// Emoji for a word typed as an emoticon
+pub fn emoji_of(word: &str) -> Option<&'static Emoji> {
+    EMOTICONS
+        .iter()
+        .find(|(emoticon, _)| *emoticon == word)
+        .and_then(|(_, glyph)| lookup(glyph))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn emoticons_stand_for_their_emoji() {
+        let tests = [
+            (":)", Some("🙂")),
+            (":-D", Some("😃")),
+            ("xD", Some("😆")),
+            (":'(", Some("😢")),
+            (":p", Some("😛")),
+            ("<3", Some("\u{2764}\u{fe0f}")),
+            ("\\o/", Some("🙌")),
+            ("¯\\_(ツ)_/¯", Some("🤷")),
+            // Only whole words, and nothing that merely looks alike
+            (":))", None),
+            ("smile:)", None),
+            (":", None),
+            ("", None),
+        ];
+        for (word, glyph) in tests {
+            assert_eq!(emoji_of(word).map(|e| e.glyph), glyph, "{word}");
+        }
+    }
+
+    #[test]
+    fn every_emoticon_has_an_emoji() {
+        for (emoticon, glyph) in EMOTICONS {
+            assert!(lookup(glyph).is_some(), "{emoticon} stands for {glyph:?}");
+        }
+    }
+}
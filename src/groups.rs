@@ -0,0 +1,159 @@
+// build.rs sorts the catalog with this too, so it only needs the emoji data
+use emoji::Emoji;
+
+// Subgroups of every group in the same order as CLDR's emoji-test.txt, the
+// emoji data doesn't keep it around
+pub const SUBGROUPS: &[(&str, &[&str])] = &[
+    (
+        "Smileys & Emotion",
+        &[
+            "face-smiling",
+            "face-affection",
+            "face-tongue",
+            "face-hand",
+            "face-neutral-skeptical",
+            "face-sleepy",
+            "face-unwell",
+            "face-hat",
+            "face-glasses",
+            "face-concerned",
+            "face-negative",
+            "face-costume",
+            "cat-face",
+            "monkey-face",
+            "emotion",
+        ],
+    ),
+    (
+        "People & Body",
+        &[
+            "hand-fingers-open",
+            "hand-fingers-partial",
+            "hand-single-finger",
+            "hand-fingers-closed",
+            "hands",
+            "hand-prop",
+            "body-parts",
+            "person",
+            "person-gesture",
+            "person-role",
+            "person-fantasy",
+            "person-activity",
+            "person-sport",
+            "person-resting",
+            "family",
+            "person-symbol",
+        ],
+    ),
+    (
+        "Animals & Nature",
+        &[
+            "animal-mammal",
+            "animal-bird",
+            "animal-amphibian",
+            "animal-reptile",
+            "animal-marine",
+            "animal-bug",
+            "plant-flower",
+            "plant-other",
+        ],
+    ),
+    (
+        "Food & Drink",
+        &[
+            "food-fruit",
+            "food-vegetable",
+            "food-prepared",
+            "food-asian",
+            "food-marine",
+            "food-sweet",
+            "drink",
+            "dishware",
+        ],
+    ),
+    (
+        "Travel & Places",
+        &[
+            "place-map",
+            "place-geographic",
+            "place-building",
+            "place-religious",
+            "place-other",
+            "transport-ground",
+            "transport-water",
+            "transport-air",
+            "hotel",
+            "time",
+            "sky & weather",
+        ],
+    ),
+    (
+        "Activities",
+        &["event", "award-medal", "sport", "game", "arts & crafts"],
+    ),
+    (
+        "Objects",
+        &[
+            "clothing",
+            "sound",
+            "music",
+            "musical-instrument",
+            "phone",
+            "computer",
+            "light & video",
+            "book-paper",
+            "money",
+            "mail",
+            "writing",
+            "office",
+            "lock",
+            "tool",
+            "science",
+            "medical",
+            "household",
+            "other-object",
+        ],
+    ),
+    (
+        "Symbols",
+        &[
+            "transport-sign",
+            "warning",
+            "arrow",
+            "religion",
+            "zodiac",
+            "av-symbol",
+            "gender",
+            "math",
+            "punctuation",
+            "currency",
+            "other-symbol",
+            "keycap",
+            "alphanum",
+            "geometric",
+        ],
+    ),
+    ("Flags", &["flag", "country-flag", "subdivision-flag"]),
+];
+
+/// Subgroups of a group, in the order they are shown
+pub fn subgroups(group: &str) -> &'static [&'static str] {
+    SUBGROUPS
+        .iter()
+        .find(|(g, _)| *g == group)
+        .map_or(&[], |(_, subgroups)| subgroups)
+}
+
+/// Sorting key of the grid: by group, then subgroup, then codepoint
+pub fn grid_order(emoji: &Emoji) -> (Option<usize>, Option<usize>, Vec<u32>) {
+    let group = SUBGROUPS.iter().position(|(g, _)| *g == emoji.group);
+    let subgroup = subgroups(emoji.group)
+        .iter()
+        .position(|s| *s == emoji.subgroup);
+    let codepoints = emoji
+        .codepoint
+        .split(' ')
+        .filter_map(|c| u32::from_str_radix(c, 16).ok())
+        .collect();
+    (group, subgroup, codepoints)
+}
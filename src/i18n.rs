@@ -0,0 +1,301 @@
+use fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+use fluent_langneg::{convert_vec_str_to_langids, negotiate, LanguageIdentifier};
+use unic_langid::CharacterDirection;
+
+// Every language the interface is translated to, English goes first as it's
+// used for anything missing in the others
+const TRANSLATIONS: &[(&str, &str)] = &[
+    ("en", include_str!("../i18n/en.ftl")),
+    ("ar", include_str!("../i18n/ar.ftl")),
+    ("de", include_str!("../i18n/de.ftl")),
+    ("es", include_str!("../i18n/es.ftl")),
+    ("fa", include_str!("../i18n/fa.ftl")),
+    ("fr", include_str!("../i18n/fr.ftl")),
+    ("he", include_str!("../i18n/he.ftl")),
+    ("it", include_str!("../i18n/it.ftl")),
+    ("ja", include_str!("../i18n/ja.ftl")),
+    ("ko", include_str!("../i18n/ko.ftl")),
+    ("nl", include_str!("../i18n/nl.ftl")),
+    ("pl", include_str!("../i18n/pl.ftl")),
+    ("pt", include_str!("../i18n/pt.ftl")),
+    ("ru", include_str!("../i18n/ru.ftl")),
+    ("ur", include_str!("../i18n/ur.ftl")),
+    ("zh", include_str!("../i18n/zh.ftl")),
+];
+
+/// Texts of the interface, in the first of the languages that has them
+pub struct Texts {
+    // Most preferred first, ending with English
+    bundles: Vec<FluentBundle<FluentResource>>,
+    rtl: bool,
+}
+
+impl Texts {
+    /// Takes the same languages the annotations are searched in, like "es_MX"
+    pub fn new(langs: &[String]) -> Self {
+        let available =
+            convert_vec_str_to_langids(TRANSLATIONS.iter().map(|(lang, _)| *lang)).unwrap();
+        // The layout follows the first language searched in, even when the
+        // interface isn't translated to it
+        let rtl = langs
+            .first()
+            .and_then(|lang| {
+                lang.replace('_', "-")
+                    .parse::<unic_langid::LanguageIdentifier>()
+                    .ok()
+            })
+            .is_some_and(|lang| lang.character_direction() == CharacterDirection::RTL);
+        let requested = langs
+            .iter()
+            .filter_map(|lang| lang.replace('_', "-").parse::<LanguageIdentifier>().ok())
+            .collect::<Vec<_>>();
+        let negotiated = negotiate::negotiate_languages(
+            &requested,
+            &available,
+            Some(&available[0]),
+            negotiate::NegotiationStrategy::Filtering,
+        );
+
+        let bundles = negotiated
+            .into_iter()
+            .map(|lang| {
+                let pos = available.iter().position(|a| a == lang).unwrap();
+                let resource = FluentResource::try_new(TRANSLATIONS[pos].1.to_string())
+                    .expect("Translations should be valid Fluent");
+                // fluent-langneg and fluent-bundle don't share their language type
+                let bundle_lang = lang.to_string().parse::<unic_langid::LanguageIdentifier>();
+                let mut bundle = FluentBundle::new(vec![bundle_lang.unwrap()]);
+                // Only numbers are put inside texts, they need no bidi isolation marks
+                bundle.set_use_isolating(false);
+                bundle.add_resource(resource).unwrap();
+                bundle
+            })
+            .collect();
+        Self { bundles, rtl }
+    }
+
+    /// Whether the interface goes from right to left
+    pub fn is_rtl(&self) -> bool {
+        self.rtl
+    }
+
+    pub fn get(&self, id: &str) -> String {
+        self.format(id, None)
+    }
+
+    pub fn get_with(&self, id: &str, args: &FluentArgs) -> String {
+        self.format(id, Some(args))
+    }
+
+    /// Name of one of CLDR's subgroups, like "sky & weather"
+    pub fn subgroup(&self, subgroup: &str) -> String {
+        self.get(&subgroup_id(subgroup))
+    }
+
+    // The id itself is shown for a text that no language has
+    fn format(&self, id: &str, args: Option<&FluentArgs>) -> String {
+        self.bundles
+            .iter()
+            .find_map(|bundle| {
+                let pattern = bundle.get_message(id)?.value()?;
+                let mut errors = Vec::new();
+                Some(
+                    bundle
+                        .format_pattern(pattern, args, &mut errors)
+                        .into_owned(),
+                )
+            })
+            .unwrap_or_else(|| id.to_string())
+    }
+}
+
+// What each language calls itself, regional variants not listed here are
+// named after their language
+const NATIVE_NAMES: &[(&str, &str)] = &[
+    ("af", "Afrikaans"),
+    ("am", "አማርኛ"),
+    ("ar", "العربية"),
+    ("as", "অসমীয়া"),
+    ("ast", "asturianu"),
+    ("az", "azərbaycan"),
+    ("be", "беларуская"),
+    ("bg", "български"),
+    ("bn", "বাংলা"),
+    ("br", "brezhoneg"),
+    ("bs", "bosanski"),
+    ("ca", "català"),
+    ("ccp", "𑄌𑄋𑄴𑄟𑄳𑄦"),
+    ("ceb", "Cebuano"),
+    ("chr", "ᏣᎳᎩ"),
+    ("ckb", "کوردیی ناوەندی"),
+    ("cs", "čeština"),
+    ("cy", "Cymraeg"),
+    ("da", "dansk"),
+    ("de", "Deutsch"),
+    ("doi", "डोगरी"),
+    ("el", "Ελληνικά"),
+    ("en", "English"),
+    ("es", "español"),
+    ("et", "eesti"),
+    ("eu", "euskara"),
+    ("fa", "فارسی"),
+    ("fi", "suomi"),
+    ("fil", "Filipino"),
+    ("fo", "føroyskt"),
+    ("fr", "français"),
+    ("ga", "Gaeilge"),
+    ("gd", "Gàidhlig"),
+    ("gl", "galego"),
+    ("gu", "ગુજરાતી"),
+    ("ha", "Hausa"),
+    ("he", "עברית"),
+    ("hi", "हिन्दी"),
+    ("hr", "hrvatski"),
+    ("hu", "magyar"),
+    ("hy", "հայերեն"),
+    ("ia", "interlingua"),
+    ("id", "Indonesia"),
+    ("ig", "Igbo"),
+    ("is", "íslenska"),
+    ("it", "italiano"),
+    ("ja", "日本語"),
+    ("jv", "Jawa"),
+    ("ka", "ქართული"),
+    ("kab", "Taqbaylit"),
+    ("kk", "қазақ тілі"),
+    ("kl", "kalaallisut"),
+    ("km", "ខ្មែរ"),
+    ("kn", "ಕನ್ನಡ"),
+    ("ko", "한국어"),
+    ("kok", "कोंकणी"),
+    ("ku", "kurdî"),
+    ("ky", "кыргызча"),
+    ("lb", "Lëtzebuergesch"),
+    ("lo", "ລາວ"),
+    ("lt", "lietuvių"),
+    ("lv", "latviešu"),
+    ("mai", "मैथिली"),
+    ("mi", "Māori"),
+    ("mk", "македонски"),
+    ("ml", "മലയാളം"),
+    ("mn", "монгол"),
+    ("mni", "মৈতৈলোন্"),
+    ("mr", "मराठी"),
+    ("ms", "Melayu"),
+    ("mt", "Malti"),
+    ("my", "မြန်မာ"),
+    ("nb", "norsk bokmål"),
+    ("ne", "नेपाली"),
+    ("nl", "Nederlands"),
+    ("nn", "norsk nynorsk"),
+    ("or", "ଓଡ଼ିଆ"),
+    ("pa", "ਪੰਜਾਬੀ"),
+    ("pa_Arab", "پنجابی"),
+    ("pcm", "Naijíriá Píjin"),
+    ("pl", "polski"),
+    ("ps", "پښتو"),
+    ("pt", "português"),
+    ("qu", "Runasimi"),
+    ("rm", "rumantsch"),
+    ("ro", "română"),
+    ("ru", "русский"),
+    ("rw", "Kinyarwanda"),
+    ("sa", "संस्कृत भाषा"),
+    ("sat", "ᱥᱟᱱᱛᱟᱲᱤ"),
+    ("sd", "سنڌي"),
+    ("si", "සිංහල"),
+    ("sk", "slovenčina"),
+    ("sl", "slovenščina"),
+    ("so", "Soomaali"),
+    ("sq", "shqip"),
+    ("sr", "српски"),
+    ("sr_Latn", "srpski"),
+    ("su", "Basa Sunda"),
+    ("sv", "svenska"),
+    ("sw", "Kiswahili"),
+    ("ta", "தமிழ்"),
+    ("te", "తెలుగు"),
+    ("tg", "тоҷикӣ"),
+    ("th", "ไทย"),
+    ("ti", "ትግርኛ"),
+    ("tk", "türkmen dili"),
+    ("to", "lea fakatonga"),
+    ("tr", "Türkçe"),
+    ("tt", "татар"),
+    ("ug", "ئۇيغۇرچە"),
+    ("uk", "українська"),
+    ("ur", "اردو"),
+    ("uz", "o‘zbek"),
+    ("vi", "Tiếng Việt"),
+    ("wo", "Wolof"),
+    ("xh", "isiXhosa"),
+    ("yo", "Èdè Yorùbá"),
+    ("yue", "粵語"),
+    ("yue_Hans", "粤语"),
+    ("zh", "中文"),
+    ("zh_Hant", "繁體中文"),
+    ("zu", "isiZulu"),
+];
+
+// Fluent ids can't have spaces nor "&"
+fn subgroup_id(subgroup: &str) -> String {
+    format!("subgroup-{}", subgroup.replace(" & ", "-and-"))
+}
+
+/// Name of a language of the annotations in that same language, with
+/// whatever isn't part of the name after it, as in "español (MX)"
+pub fn native_name(lang: &str) -> String {
+    let mut name = lang;
+    while let Some((rest, _)) = name.rsplit_once('_') {
+        if NATIVE_NAMES.iter().any(|(code, _)| *code == name) {
+            break;
+        }
+        name = rest;
+    }
+    let native = NATIVE_NAMES
+        .iter()
+        .find(|(code, _)| *code == name)
+        .map_or(name, |(_, native)| native);
+    match lang[name.len()..].strip_prefix('_') {
+        Some(rest) => format!("{native} ({})", rest.replace('_', "-")),
+        None => native.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_subgroup_is_translated() {
+        let subgroups = emoji::lookup_by_glyph::iter_emoji()
+            .filter(|e| e.group != "Component")
+            .map(|e| subgroup_id(e.subgroup))
+            .collect::<std::collections::HashSet<_>>();
+        for (lang, ftl) in TRANSLATIONS {
+            assert!(FluentResource::try_new(ftl.to_string()).is_ok(), "{lang}");
+            for id in &subgroups {
+                assert!(ftl.contains(&format!("\n{id} = ")), "{id} in {lang}");
+            }
+        }
+    }
+
+    #[test]
+    fn languages_are_named_in_themselves() {
+        let tests = [
+            ("es", "español"),
+            ("es_419", "español (419)"),
+            ("sr", "српски"),
+            ("sr_Latn", "srpski"),
+            ("sr_Latn_BA", "srpski (BA)"),
+            ("sr_Cyrl_BA", "српски (Cyrl-BA)"),
+            ("pa", "ਪੰਜਾਬੀ"),
+            ("pa_Arab", "پنجابی"),
+            // Unknown languages keep their code
+            ("xx_YY", "xx (YY)"),
+        ];
+        for (lang, name) in tests {
+            assert_eq!(native_name(lang), name, "{lang}");
+        }
+    }
+}
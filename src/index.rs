@@ -0,0 +1,218 @@
+// Also compiled into build.rs, which writes the embedded indexes with it
+use std::fs;
+use std::path::Path;
+
+use emoji::{lookup_by_glyph::iter_emoji, Emoji};
+use tantivy::{
+    doc,
+    schema::{IndexRecordOption, Schema, TextFieldIndexing, TextOptions, INDEXED, STORED, TEXT},
+    tokenizer::{
+        AsciiFoldingFilter, Language, LowerCaser, NgramTokenizer, RemoveLongFilter,
+        SimpleTokenizer, Stemmer, TextAnalyzer,
+    },
+    Index, TantivyDocument,
+};
+
+// Lowercased and without diacritics, "Corazón" is found by "corazon"
+const FOLDED_TOKENIZER: &str = "folded";
+// Japanese and Chinese don't separate words, nor Korean its compounds, so
+// every piece of one or two characters is indexed to find them inside others
+const NGRAM_TOKENIZER: &str = "ngram";
+
+pub const ANNOTATION: &str = "annotation";
+pub const ANNOTATION_FOLDED: &str = "annotation_folded";
+// Only for languages with a stemmer, "dancing" is found by "dance"
+pub const ANNOTATION_STEMMED: &str = "annotation_stemmed";
+pub const GLYPH: &str = "glyph";
+pub const GROUP: &str = "group";
+pub const SUBGROUP: &str = "subgroup";
+pub const VERSION: &str = "version";
+pub const IS_VARIANT: &str = "is_variant";
+
+/// Unicode version an emoji was introduced in, as it's written. Its f32 would
+/// turn 13.1 into 13.100000381 and miss `version:13.1`
+pub fn version_of(emoji: &Emoji) -> f64 {
+    emoji.introduction_version.to_string().parse().unwrap()
+}
+
+/// Whether the annotations of a language are split in pieces instead of words
+pub fn is_cjk(lang: &str) -> bool {
+    ["ja", "zh", "yue", "ko"]
+        .iter()
+        .any(|cjk| lang == *cjk || lang.starts_with(&format!("{cjk}_")))
+}
+
+const STEMMERS: &[(&str, Language)] = &[
+    ("ar", Language::Arabic),
+    ("da", Language::Danish),
+    ("nl", Language::Dutch),
+    ("en", Language::English),
+    ("fi", Language::Finnish),
+    ("fr", Language::French),
+    ("de", Language::German),
+    ("el", Language::Greek),
+    ("hu", Language::Hungarian),
+    ("it", Language::Italian),
+    ("nb", Language::Norwegian),
+    ("nn", Language::Norwegian),
+    ("pt", Language::Portuguese),
+    ("ro", Language::Romanian),
+    ("ru", Language::Russian),
+    ("es", Language::Spanish),
+    ("sv", Language::Swedish),
+    ("ta", Language::Tamil),
+    ("tr", Language::Turkish),
+];
+
+// Regional variants ("es_MX") use the stemmer of their language
+fn stemmer_of(lang: &str) -> Option<&'static str> {
+    let base = lang.split('_').next().unwrap_or(lang);
+    STEMMERS
+        .iter()
+        .find(|(stem_lang, _)| *stem_lang == base)
+        .map(|(stem_lang, _)| *stem_lang)
+}
+
+fn stemmed_tokenizer(stem_lang: &str) -> String {
+    format!("stemmed_{stem_lang}")
+}
+
+pub fn schema(lang: &str) -> Schema {
+    let mut schema_builder = Schema::builder();
+    if is_cjk(lang) {
+        schema_builder.add_text_field(
+            ANNOTATION,
+            TextOptions::default().set_indexing_options(
+                TextFieldIndexing::default()
+                    .set_tokenizer(NGRAM_TOKENIZER)
+                    .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+            ),
+        );
+    } else {
+        schema_builder.add_text_field(ANNOTATION, TEXT);
+    }
+    schema_builder.add_text_field(
+        ANNOTATION_FOLDED,
+        TextOptions::default().set_indexing_options(
+            TextFieldIndexing::default()
+                .set_tokenizer(FOLDED_TOKENIZER)
+                .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+        ),
+    );
+    schema_builder.add_text_field(GLYPH, TEXT | STORED);
+    if let Some(stem_lang) = stemmer_of(lang) {
+        schema_builder.add_text_field(
+            ANNOTATION_STEMMED,
+            TextOptions::default().set_indexing_options(
+                TextFieldIndexing::default()
+                    .set_tokenizer(&stemmed_tokenizer(stem_lang))
+                    .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+            ),
+        );
+    }
+    // Only there to filter by them
+    schema_builder.add_text_field(GROUP, TEXT);
+    schema_builder.add_text_field(SUBGROUP, TEXT);
+    schema_builder.add_f64_field(VERSION, INDEXED);
+    schema_builder.add_bool_field(IS_VARIANT, INDEXED);
+    schema_builder.build()
+}
+
+/// Our own tokenizers have to be known by an index before it's used
+pub fn register_tokenizers(index: &Index) {
+    index.tokenizers().register(
+        FOLDED_TOKENIZER,
+        TextAnalyzer::builder(SimpleTokenizer::default())
+            .filter(RemoveLongFilter::limit(40))
+            .filter(LowerCaser)
+            .filter(AsciiFoldingFilter)
+            .build(),
+    );
+    for (stem_lang, language) in STEMMERS {
+        index.tokenizers().register(
+            &stemmed_tokenizer(stem_lang),
+            TextAnalyzer::builder(SimpleTokenizer::default())
+                .filter(RemoveLongFilter::limit(40))
+                .filter(LowerCaser)
+                .filter(Stemmer::new(*language))
+                .filter(AsciiFoldingFilter)
+                .build(),
+        );
+    }
+    index.tokenizers().register(
+        NGRAM_TOKENIZER,
+        TextAnalyzer::builder(NgramTokenizer::all_ngrams(1, 2).unwrap())
+            .filter(LowerCaser)
+            .build(),
+    );
+}
+
+/// Writes an index with the given keywords of each emoji, in a language,
+/// replacing whatever was in the directory
+pub fn write_keywords(
+    path: &Path,
+    lang: &str,
+    keywords: impl IntoIterator<Item = (&'static Emoji, String)>,
+) -> tantivy::Result<()> {
+    let schema = schema(lang);
+    let annotation = schema.get_field(ANNOTATION)?;
+    let annotation_folded = schema.get_field(ANNOTATION_FOLDED)?;
+    let annotation_stemmed = schema.get_field(ANNOTATION_STEMMED).ok();
+    let glyph = schema.get_field(GLYPH)?;
+    let group = schema.get_field(GROUP)?;
+    let subgroup = schema.get_field(SUBGROUP)?;
+    let version = schema.get_field(VERSION)?;
+    let is_variant = schema.get_field(IS_VARIANT)?;
+
+    if path.exists() {
+        fs::remove_dir_all(path)?;
+    }
+    fs::create_dir_all(path)?;
+    let index = Index::create_in_dir(path, schema)?;
+    register_tokenizers(&index);
+    let mut index_writer = index.writer::<TantivyDocument>(15_000_000)?;
+    for (emoji, emoji_keywords) in keywords {
+        let mut document = doc!(
+            annotation => emoji_keywords.clone(),
+            annotation_folded => emoji_keywords.clone(),
+            glyph => emoji.glyph,
+            group => emoji.group,
+            subgroup => emoji.subgroup,
+            version => version_of(emoji),
+            is_variant => emoji.is_variant
+        );
+        if let Some(annotation_stemmed) = annotation_stemmed {
+            document.add_text(annotation_stemmed, emoji_keywords);
+        }
+        index_writer.add_document(document)?;
+    }
+
+    index_writer.commit()?;
+    index_writer.wait_merging_threads()
+}
+
+/// Writes the index of a single language, replacing whatever was in the directory
+fn write_index(path: &Path, lang: &str) -> tantivy::Result<()> {
+    write_keywords(
+        path,
+        lang,
+        iter_emoji().flat_map(|emoji| {
+            emoji
+                .annotations
+                .iter()
+                .filter(|a| a.lang == lang)
+                .map(move |a| (emoji, a.keywords.join(",")))
+        }),
+    )
+}
+
+/// Writes the index of each language into a directory of its own under `dir`
+pub fn write_indexes<'a>(
+    dir: &Path,
+    langs: impl IntoIterator<Item = &'a str>,
+) -> tantivy::Result<()> {
+    for lang in langs {
+        write_index(&dir.join(lang), lang)?;
+    }
+    Ok(())
+}
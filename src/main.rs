@@ -1,28 +1,44 @@
+mod catalog;
+mod emoticons;
+mod groups;
+mod i18n;
+// Shared with build.rs
+mod index;
+mod query;
+mod readonly;
 mod search;
 mod theme;
+mod variants;
 
-use std::cell::RefCell;
+use std::borrow::Cow;
+use std::cell::{Cell, Ref, RefCell};
+use std::collections::HashMap;
 use std::env;
 use std::fs::File;
 use std::io::{BufReader, BufWriter};
 use std::path::PathBuf;
 use std::slice::Iter;
-use std::str::FromStr;
-use std::sync::LazyLock;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock};
+use std::time::{Duration, Instant};
 
 use emoji::Emoji;
+use fluent_bundle::FluentArgs;
+use i18n::Texts;
 use iced::alignment::{self, Horizontal, Vertical};
-use iced::widget::operation::focus;
+use iced::widget::operation::{focus, scroll_to, AbsoluteOffset};
 use iced::widget::text_input::Icon;
 use iced::widget::{
-    button, column, container, responsive, row, scrollable, text, text_input, Container, Id,
+    button, column, container, mouse_area, responsive, row, scrollable, space, text, text_input,
+    Container, Id, Row,
 };
 use iced::{
     event, keyboard, window, Element, Font, Length, Pixels, Renderer, Settings, Subscription, Task,
 };
-use search::{SearchEngine, TantivySearch};
+use search::{NameSearch, Scope, SearchEngine, SearchResults, TantivySearch};
 use serde::{Deserialize, Serialize};
 use theme::RoundedTheme;
+use variants::{GenderGroup, Preference, SkinTone, ToneFamily};
 
 use crate::theme::{ButtonStyle, TextType};
 
@@ -36,11 +52,19 @@ mod conf {
     pub const MAX_HISTORY_SIZE: usize = 80;
     pub const CAT_EMOJI_FONT_SIZE: u32 = 23;
     pub const CAT_EMOJI_SIZE: u32 = 35;
+    pub const LONG_PRESS_MS: u64 = 500;
+    pub const SECTION_HEADER_SIZE: u32 = 20;
+    pub const SUBSECTION_HEADER_SIZE: u32 = 16;
+    pub const SEARCH_DEBOUNCE_MS: u64 = 120;
+    pub const SEARCH_PAGE_SIZE: usize = 72;
+    // How close to the end of the results the next page is loaded
+    pub const LOAD_MORE_MARGIN: f32 = 100.0;
 }
 
 // Application's constants
 const VER_PADDING: u32 = 4;
 const HOR_PADDING: u32 = 7;
+const EMOJI_PADDING: f32 = 7.5;
 const WINDOW_RATIO: f32 = 1.618034;
 const SCROLLBAR_PADDING: u32 = 12;
 const EMOJI_FONT: Font = Font::with_name("Noto Color Emoji");
@@ -56,14 +80,44 @@ fn make_conf_dir() {
     std::fs::create_dir_all(OPTIONS_PATH.parent().expect("")).expect("")
 }
 
+fn get_cache_dir() -> PathBuf {
+    PathBuf::from(env::var("XDG_CACHE_HOME").unwrap_or(format!(
+        "{}/.var/app/io.github.sheosi.bmoji/cache",
+        env::var("HOME").unwrap()
+    )))
+}
+
 static OPTIONS_PATH: LazyLock<PathBuf> =
     LazyLock::new(|| get_conf_dir().join("bmoji/options.json"));
 
+// Extra keywords for any glyph, as in {"🦀": ["rust", "ferris"]}
+static KEYWORDS_PATH: LazyLock<PathBuf> =
+    LazyLock::new(|| get_conf_dir().join("bmoji/keywords.json"));
+
+static USER_INDEX_PATH: LazyLock<PathBuf> = LazyLock::new(|| get_cache_dir().join("bmoji/index"));
+
 fn main() -> iced::Result {
+    let args = env::args().collect::<Vec<_>>();
+    if let [_, flag, data_dir] = args.as_slice() {
+        // Installs the index of every language read-only, instead of embedding them
+        if flag == "--write-index" {
+            let index_dir = PathBuf::from(data_dir).join("index");
+            let langs = emoji::ANNOTATION_LANGS_AVAILABLE.iter().copied();
+            if let Err(err) = index::write_indexes(&index_dir, langs) {
+                eprintln!(
+                    "Couldn't write the indexes into {}: {err}",
+                    index_dir.display()
+                );
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+    }
+
     let width = ((conf::EMOJI_SIZE + conf::SPACING) * conf::EMOJI_PER_LINE
         + VER_PADDING * 2
         + SCROLLBAR_PADDING) as f32;
-    let height = ((width as f32) / WINDOW_RATIO).ceil();
+    let height = (width / WINDOW_RATIO).ceil();
 
     let app_settings = Settings {
         antialiasing: true,
@@ -98,6 +152,15 @@ fn main() -> iced::Result {
 struct BmojiOptions {
     #[serde(default)]
     history: EmojiHistory,
+    #[serde(flatten)]
+    preference: Preference,
+    // Languages to search in, most preferred first. The locale ones and
+    // English are used when empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    languages: Vec<String>,
+    // Picked with the language button, searched in before the others
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    chosen_language: Option<String>,
 }
 
 #[derive(Debug, Default, Deserialize, Serialize)]
@@ -119,9 +182,7 @@ impl EmojiHistory {
     fn emojis(&self) -> Vec<&'static Emoji> {
         self.0
             .iter()
-            .map(|g| emoji::lookup_by_glyph::lookup(g))
-            .filter(Option::is_some)
-            .map(Option::unwrap)
+            .filter_map(|g| emoji::lookup_by_glyph::lookup(g))
             .collect()
     }
 
@@ -152,12 +213,15 @@ impl BmojiOptions {
                     .cloned()
                     .collect(),
             ),
+            preference: self.preference,
+            languages: self.languages.clone(),
+            chosen_language: self.chosen_language.clone(),
         };
         serde_json::to_writer(writer, &options_with_lim_history).unwrap();
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 enum EmojiCategory {
     History,
     Activities,
@@ -171,6 +235,163 @@ enum EmojiCategory {
     TravelAndPlaces,
 }
 
+impl EmojiCategory {
+    // Same order as the category bar
+    const ALL: [EmojiCategory; 10] = [
+        EmojiCategory::History,
+        EmojiCategory::SmileysAndEmotion,
+        EmojiCategory::PeopleAndBody,
+        EmojiCategory::Activities,
+        EmojiCategory::AnimalsAndNature,
+        EmojiCategory::Flags,
+        EmojiCategory::FoodAndDrink,
+        EmojiCategory::Objects,
+        EmojiCategory::Symbols,
+        EmojiCategory::TravelAndPlaces,
+    ];
+
+    fn group(self) -> Option<&'static str> {
+        match self {
+            EmojiCategory::History => None,
+            EmojiCategory::Activities => Some("Activities"),
+            EmojiCategory::AnimalsAndNature => Some("Animals & Nature"),
+            EmojiCategory::Flags => Some("Flags"),
+            EmojiCategory::FoodAndDrink => Some("Food & Drink"),
+            EmojiCategory::Objects => Some("Objects"),
+            EmojiCategory::PeopleAndBody => Some("People & Body"),
+            EmojiCategory::SmileysAndEmotion => Some("Smileys & Emotion"),
+            EmojiCategory::Symbols => Some("Symbols"),
+            EmojiCategory::TravelAndPlaces => Some("Travel & Places"),
+        }
+    }
+
+    // Id of its title in the translations
+    fn title(self) -> &'static str {
+        match self {
+            EmojiCategory::History => "category-history",
+            EmojiCategory::Activities => "category-activities",
+            EmojiCategory::AnimalsAndNature => "category-animals-and-nature",
+            EmojiCategory::Flags => "category-flags",
+            EmojiCategory::FoodAndDrink => "category-food-and-drink",
+            EmojiCategory::Objects => "category-objects",
+            EmojiCategory::PeopleAndBody => "category-people-and-body",
+            EmojiCategory::SmileysAndEmotion => "category-smileys-and-emotion",
+            EmojiCategory::Symbols => "category-symbols",
+            EmojiCategory::TravelAndPlaces => "category-travel-and-places",
+        }
+    }
+
+    fn glyph(self) -> &'static str {
+        match self {
+            EmojiCategory::History => "🕑",
+            EmojiCategory::Activities => "⚽",
+            EmojiCategory::AnimalsAndNature => "🐻",
+            EmojiCategory::Flags => "🎌",
+            EmojiCategory::FoodAndDrink => "🍔",
+            EmojiCategory::Objects => "💡",
+            EmojiCategory::PeopleAndBody => "🧑",
+            EmojiCategory::SmileysAndEmotion => "😃",
+            EmojiCategory::Symbols => "💕",
+            EmojiCategory::TravelAndPlaces => "🚀",
+        }
+    }
+}
+
+// A run of emojis in the grid, search results are the only ones without a header
+struct Section {
+    category: Option<EmojiCategory>,
+    emojis: Cow<'static, [&'static Emoji]>,
+}
+
+enum GridLine<'a> {
+    Header(EmojiCategory),
+    SubHeader(&'static str),
+    Row(&'a [&'static Emoji]),
+}
+
+impl GridLine<'_> {
+    fn height(&self) -> f32 {
+        match self {
+            GridLine::Header(_) => conf::SECTION_HEADER_SIZE as f32,
+            GridLine::SubHeader(_) => conf::SUBSECTION_HEADER_SIZE as f32,
+            GridLine::Row(_) => conf::EMOJI_SIZE as f32,
+        }
+    }
+}
+
+fn grid_lines(sections: &[Section], per_row: usize) -> Vec<GridLine<'_>> {
+    let mut lines = Vec::new();
+    for section in sections {
+        match section.category {
+            // Categories are split further into their subgroups
+            Some(category) if category.group().is_some() => {
+                lines.push(GridLine::Header(category));
+                for subgroup in section.emojis.chunk_by(|a, b| a.subgroup == b.subgroup) {
+                    lines.push(GridLine::SubHeader(subgroup[0].subgroup));
+                    lines.extend(subgroup.chunks(per_row.max(1)).map(GridLine::Row));
+                }
+            }
+            Some(category) => {
+                lines.push(GridLine::Header(category));
+                lines.extend(section.emojis.chunks(per_row.max(1)).map(GridLine::Row));
+            }
+            None => lines.extend(section.emojis.chunks(per_row.max(1)).map(GridLine::Row)),
+        }
+    }
+    lines
+}
+
+// Only the lines around the viewport are built, the rest is replaced by
+// empty space above and below them. No space at all is zero, and shouldn't
+// be put in the grid since it would still get spacing
+fn visible_lines(
+    lines: Vec<GridLine<'_>>,
+    offset: f32,
+    height: f32,
+) -> (f32, Vec<GridLine<'_>>, f32) {
+    let spacing = conf::SPACING as f32;
+    // Some extra lines at both ends so that fast scrolling doesn't show blanks
+    let from = offset - height;
+    let to = offset + height * 2.0;
+
+    let mut top = 0.0;
+    let mut bottom = 0.0;
+    let mut visible = Vec::new();
+    let mut y = 0.0;
+    for line in lines {
+        let line_height = line.height();
+        if y + line_height < from {
+            top = y + line_height;
+        } else if y > to {
+            bottom += line_height + spacing;
+        } else {
+            visible.push(line);
+        }
+        y += line_height + spacing;
+    }
+
+    // The column already puts spacing between the spaces and the lines
+    (top, visible, (bottom - spacing).max(0.0))
+}
+
+type Offsets = Vec<(EmojiCategory, f32)>;
+
+// Where each section starts inside of the grid's scrollable
+fn section_offsets(sections: &[Section], per_row: usize) -> Offsets {
+    let mut offsets = Vec::new();
+    let mut y = 0.0;
+    for line in grid_lines(sections, per_row) {
+        if let GridLine::Header(category) = line {
+            offsets.push((category, y));
+        }
+        y += line.height() + conf::SPACING as f32;
+    }
+    offsets
+}
+
+// The query and the category it was restricted to, if any
+type SearchKey = (Option<EmojiCategory>, String);
+
 struct Bmoji {
     has_been_interacted: bool,
     search_query: String,
@@ -179,17 +400,35 @@ struct Bmoji {
     first_emoji: RefCell<Option<&'static Emoji>>,
     search_input_id: Id,
     options: BmojiOptions,
-    search_eng: TantivySearch,
+    // Searched in, most preferred first
+    langs: Vec<String>,
+    // In the same languages the annotations are searched in
+    texts: Texts,
+    lang_picker: bool,
+    // Loaded in the background, names are searched until then
+    search_eng: Option<Arc<TantivySearch>>,
+    // None while the first results of a query are on their way
+    search_results: Option<SearchResults>,
+    search_cache: HashMap<SearchKey, SearchResults>,
+    // Whether searches are restricted to the current category
+    scoped: bool,
+    // Bumped on every query, searches that are no longer the latest give up
+    search_generation: Arc<AtomicU64>,
+    modifiers: keyboard::Modifiers,
+    pressed: Option<(&'static Emoji, Instant)>,
+    selection: Vec<&'static str>,
+    grid_id: Id,
+    grid_columns: Cell<usize>,
+    // Where each category starts, and for how many columns that was worked out
+    category_offsets: RefCell<Option<(usize, Offsets)>>,
+    grid_offset: f32,
+    subgroup: Option<&'static str>,
 }
 
 impl Default for Bmoji {
     fn default() -> Self {
-        let langs = get_langs();
-        let langs_ref = langs.iter().map(|l| l.as_str()).collect::<Vec<&str>>();
-
-        let search_eng = search::TantivySearch::new(&langs_ref);
-
         let options = BmojiOptions::load();
+        let langs = get_langs(options.chosen_language.as_deref(), &options.languages);
         let search_input_id = Id::unique();
         Self {
             has_been_interacted: false,
@@ -202,14 +441,39 @@ impl Default for Bmoji {
             },
             first_emoji: RefCell::new(None),
             search_input_id: search_input_id.clone(),
+            texts: Texts::new(&langs),
+            langs,
+            lang_picker: false,
             options,
-            search_eng,
+            search_eng: None,
+            search_results: None,
+            search_cache: HashMap::new(),
+            scoped: false,
+            search_generation: Arc::new(AtomicU64::new(0)),
+            modifiers: keyboard::Modifiers::default(),
+            pressed: None,
+            selection: Vec::new(),
+            grid_id: Id::unique(),
+            grid_columns: Cell::new(conf::EMOJI_PER_LINE as usize),
+            category_offsets: RefCell::new(None),
+            grid_offset: 0.0,
+            subgroup: None,
         }
     }
 }
 
 struct VariantPicker {
     emoji: &'static Emoji,
+    preview: Option<&'static Emoji>,
+}
+
+impl VariantPicker {
+    fn new(emoji: &'static Emoji) -> Self {
+        Self {
+            emoji,
+            preview: None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -222,61 +486,230 @@ enum BmojiMessage {
     GainFocus,
     SimpleInteraction,
     Glyph(&'static str),
+    GlyphPressed(&'static Emoji),
+    GlyphReleased,
+    // Sent once a press has lasted long enough, it's still held if it's the same one
+    LongPress(Instant),
     ShowGlyphVariants(&'static Emoji),
+    VariantChosen(&'static Emoji),
+    PreviewVariant(&'static Emoji),
+    ModifiersChanged(keyboard::Modifiers),
     CategoryChanged(EmojiCategory),
+    CopySelection,
+    ClearSelection,
+    GridScrolled(scrollable::Viewport),
+    SubgroupToggled(&'static str),
+    SearchReady(Vec<String>, Arc<TantivySearch>),
+    SearchDone(u64, SearchKey, SearchResults),
+    ScopeToggled,
+    LanguagePickerToggled,
+    LanguageChosen(&'static str),
 }
 
 fn emoji_button<'a>(
     glyph: &'static str,
     has_variants: bool,
 ) -> iced::widget::Button<'a, BmojiMessage, RoundedTheme> {
-    button(
-        text(glyph)
-            .size(conf::EMOJI_FONT_SIZE)
-            .line_height(conf::EMOJI_LINE_HEIGHT)
-            .align_x(Horizontal::Center)
-            .align_y(Vertical::Center)
-            .font(EMOJI_FONT),
-    )
-    .height(conf::EMOJI_SIZE)
-    .width(conf::EMOJI_SIZE)
-    .padding(7.5)
-    .class(if has_variants {
-        ButtonStyle::Category
+    emoji_cell(emoji_text(glyph), has_variants).padding(EMOJI_PADDING)
+}
+
+fn emoji_text<'a>(glyph: &'static str) -> iced::widget::Text<'a, RoundedTheme> {
+    text(glyph)
+        .size(conf::EMOJI_FONT_SIZE)
+        .line_height(conf::EMOJI_LINE_HEIGHT)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+        .font(EMOJI_FONT)
+}
+
+fn emoji_cell<'a>(
+    content: impl Into<Element<'a, BmojiMessage, RoundedTheme>>,
+    has_variants: bool,
+) -> iced::widget::Button<'a, BmojiMessage, RoundedTheme> {
+    button(content)
+        .height(conf::EMOJI_SIZE)
+        .width(conf::EMOJI_SIZE)
+        .class(if has_variants {
+            ButtonStyle::Category
+        } else {
+            ButtonStyle::Emoji
+        })
+}
+
+// The children go from the right for right-to-left languages
+fn mirrored_row<'a>(
+    rtl: bool,
+    children: impl IntoIterator<Item = Element<'a, BmojiMessage, RoundedTheme>>,
+) -> Row<'a, BmojiMessage, RoundedTheme> {
+    let mut children = children.into_iter().collect::<Vec<_>>();
+    if rtl {
+        children.reverse();
+    }
+    row(children)
+}
+
+// Where lines of text and emojis begin
+fn reading_start(rtl: bool) -> Horizontal {
+    if rtl {
+        Horizontal::Right
     } else {
-        ButtonStyle::Emoji
-    })
+        Horizontal::Left
+    }
 }
 
-fn grid_row<'a>(emoji_row: &[&'static Emoji]) -> Element<'a, BmojiMessage, RoundedTheme, Renderer> {
+fn grid_row<'a>(
+    emoji_row: &[&'static Emoji],
+    preference: Preference,
+    rtl: bool,
+) -> Element<'a, BmojiMessage, RoundedTheme, Renderer> {
     let button_row = emoji_row
         .iter()
         .map(|emoji_data| {
-            let is_variant = emoji_data.variants.len() > 1;
-            emoji_button(emoji_data.glyph, is_variant)
-                .on_press(if is_variant {
-                    BmojiMessage::ShowGlyphVariants(emoji_data)
-                } else {
-                    BmojiMessage::Glyph(emoji_data.glyph)
-                })
-                .into()
+            let is_variant = variants::has_variants(emoji_data);
+            let shown = variants::preferred(emoji_data, preference);
+            if is_variant {
+                // Left click copies right away, right click or a long press shows the variants.
+                // The press is caught inside the button, which is left enabled
+                let held = mouse_area(container(emoji_text(shown.glyph)).padding(EMOJI_PADDING))
+                    .on_press(BmojiMessage::GlyphPressed(emoji_data))
+                    .on_release(BmojiMessage::GlyphReleased)
+                    .on_right_press(BmojiMessage::ShowGlyphVariants(emoji_data))
+                    .interaction(iced::mouse::Interaction::Pointer);
+                emoji_cell(held, is_variant)
+                    .padding(0)
+                    .on_press(BmojiMessage::Glyph(shown.glyph))
+                    .into()
+            } else {
+                emoji_button(shown.glyph, is_variant)
+                    .on_press(BmojiMessage::Glyph(shown.glyph))
+                    .into()
+            }
         })
         .collect::<Vec<_>>();
-    row(button_row).spacing(conf::SPACING).into()
+    mirrored_row(rtl, button_row).spacing(conf::SPACING).into()
+}
+
+// One tone per person: rows are the first person's tone, columns the second's
+fn tones_grid<'a>(
+    family: &'static ToneFamily,
+    rtl: bool,
+) -> Element<'a, BmojiMessage, RoundedTheme> {
+    fn swatch<'a>(tone: SkinTone) -> Element<'a, BmojiMessage, RoundedTheme> {
+        text(tone.modifier())
+            .font(EMOJI_FONT)
+            .size(conf::EMOJI_FONT_SIZE)
+            .width(conf::EMOJI_SIZE)
+            .align_x(Horizontal::Center)
+            .into()
+    }
+
+    let base = mouse_area(
+        emoji_button(family.base.glyph, false).on_press(BmojiMessage::VariantChosen(family.base)),
+    )
+    .on_enter(BmojiMessage::PreviewVariant(family.base))
+    .into();
+    let header = mirrored_row(rtl, std::iter::once(base).chain(SkinTone::ALL.map(swatch)))
+        .spacing(conf::SPACING);
+
+    let tone_rows = SkinTone::ALL.map(|first| {
+        let cells = SkinTone::ALL.map(|second| match family.with_tones(&[first, second]) {
+            Some(emoji) => mouse_area(
+                emoji_button(emoji.glyph, false).on_press(BmojiMessage::VariantChosen(emoji)),
+            )
+            .on_enter(BmojiMessage::PreviewVariant(emoji))
+            .into(),
+            None => emoji_button("", false).into(),
+        });
+        mirrored_row(rtl, std::iter::once(swatch(first)).chain(cells))
+            .spacing(conf::SPACING)
+            .into()
+    });
+
+    scrollable(
+        column(std::iter::once(header.into()).chain(tone_rows))
+            .spacing(conf::SPACING)
+            .width(Length::Fill)
+            .align_x(reading_start(rtl)),
+    )
+    .height(Length::Fill)
+    .into()
+}
+
+// Every gender of an emoji, each in every tone
+fn gender_grid<'a>(
+    group: &'static GenderGroup,
+    rtl: bool,
+) -> Element<'a, BmojiMessage, RoundedTheme> {
+    let gender_rows = group.members().iter().map(|(_, emoji)| {
+        let forms = match variants::family_of(emoji) {
+            Some(family) => family.members(),
+            None => vec![*emoji],
+        };
+        mirrored_row(
+            rtl,
+            forms.into_iter().map(|emoji| {
+                mouse_area(
+                    emoji_button(emoji.glyph, false).on_press(BmojiMessage::VariantChosen(emoji)),
+                )
+                .on_enter(BmojiMessage::PreviewVariant(emoji))
+                .into()
+            }),
+        )
+        .spacing(conf::SPACING)
+        .into()
+    });
+
+    scrollable(
+        column(gender_rows)
+            .spacing(conf::SPACING)
+            .width(Length::Fill)
+            .align_x(reading_start(rtl)),
+    )
+    .height(Length::Fill)
+    .into()
 }
 
 impl Bmoji {
-    fn grid_of(&self, elements: Vec<&'static Emoji>) -> Element<'_, BmojiMessage, RoundedTheme> {
+    fn grid_of(&self, sections: Vec<Section>) -> Element<'_, BmojiMessage, RoundedTheme> {
         responsive(move |size| {
             let max_per_row =
                 (size.width / ((conf::EMOJI_SIZE + conf::SPACING) as f32)).floor() as usize;
-            let rows = elements
-                .chunks(max_per_row)
-                .map(grid_row)
-                .collect::<Vec<_>>();
+            // Needed later on to know where each section is
+            self.grid_columns.set(max_per_row);
+            let (top, lines, bottom) = visible_lines(
+                grid_lines(&sections, max_per_row),
+                self.grid_offset,
+                size.height,
+            );
+            let rtl = self.texts.is_rtl();
+            let rows = lines.into_iter().map(|line| match line {
+                GridLine::Header(category) => text(self.texts.get(category.title()))
+                    .width(Length::Fill)
+                    .align_x(reading_start(rtl))
+                    .height(conf::SECTION_HEADER_SIZE)
+                    .align_y(Vertical::Bottom)
+                    .class(TextType::Disabled)
+                    .into(),
+                GridLine::SubHeader(subgroup) => text(self.texts.subgroup(subgroup))
+                    .size(12)
+                    .width(Length::Fill)
+                    .align_x(reading_start(rtl))
+                    .height(conf::SUBSECTION_HEADER_SIZE)
+                    .align_y(Vertical::Bottom)
+                    .class(TextType::Disabled)
+                    .into(),
+                GridLine::Row(emoji_row) => grid_row(emoji_row, self.options.preference, rtl),
+            });
 
-            let emoji_grid = column(rows).spacing(conf::SPACING).padding(0);
+            let spacer = |height: f32| (height > 0.0).then(|| space().height(height).into());
+            let emoji_grid = column(spacer(top).into_iter().chain(rows).chain(spacer(bottom)))
+                .spacing(conf::SPACING)
+                .padding(0)
+                .width(Length::Fill)
+                .align_x(reading_start(rtl));
             scrollable(emoji_grid)
+                .id(self.grid_id.clone())
+                .on_scroll(BmojiMessage::GridScrolled)
                 .width(Length::Fill)
                 .height(Length::Fill)
                 .into()
@@ -285,14 +718,186 @@ impl Bmoji {
         .into()
     }
 
-    fn copy_and_quit(&mut self, glyph: &'static str) -> Task<BmojiMessage> {
-        self.options.history.add(glyph.to_string());
+    // Only worked out again when the grid changes its width
+    fn category_offsets(&self) -> Ref<'_, Offsets> {
+        let columns = self.grid_columns.get();
+        let is_stale = self
+            .category_offsets
+            .borrow()
+            .as_ref()
+            .is_none_or(|(cached, _)| *cached != columns);
+        if is_stale {
+            let offsets = section_offsets(&self.category_sections(), columns);
+            *self.category_offsets.borrow_mut() = Some((columns, offsets));
+        }
+        Ref::map(self.category_offsets.borrow(), |cached| {
+            &cached.as_ref().unwrap().1
+        })
+    }
+
+    // Every category one after the other, history first when there's any.
+    // With a subgroup chip selected only that subgroup is left
+    fn category_sections(&self) -> Vec<Section> {
+        if let Some(subgroup) = self.subgroup {
+            let emojis = self
+                .category
+                .group()
+                .map(catalog::emojis_of)
+                .unwrap_or_default()
+                .iter()
+                .filter(|e| e.subgroup == subgroup)
+                .copied()
+                .collect::<Vec<_>>();
+            return vec![Section {
+                category: Some(self.category),
+                emojis: Cow::Owned(emojis),
+            }];
+        }
+
+        EmojiCategory::ALL
+            .iter()
+            .map(|category| Section {
+                category: Some(*category),
+                emojis: match category.group() {
+                    Some(group) => Cow::Borrowed(catalog::emojis_of(group)),
+                    None => Cow::Owned(self.options.history.emojis()),
+                },
+            })
+            .filter(|section| !section.emojis.is_empty())
+            .collect()
+    }
+
+    fn subgroup_chips(&self) -> Option<Element<'_, BmojiMessage, RoundedTheme>> {
+        let group = self.category.group()?;
+        if !self.search_query.is_empty() || self.variant_picker.is_some() || self.lang_picker {
+            return None;
+        }
+
+        let chips = catalog::subgroups(group).iter().map(|subgroup| {
+            button(text(self.texts.subgroup(subgroup)).size(12))
+                .on_press(BmojiMessage::SubgroupToggled(subgroup))
+                .class(if self.subgroup == Some(*subgroup) {
+                    ButtonStyle::Category
+                } else {
+                    ButtonStyle::Plain
+                })
+                .padding([2, 6])
+                .into()
+        });
+
+        let rtl = self.texts.is_rtl();
+        Some(
+            scrollable(mirrored_row(rtl, chips).spacing(2))
+                .direction(scrollable::Direction::Horizontal(
+                    scrollable::Scrollbar::new().width(2).scroller_width(2),
+                ))
+                .anchor_x(if rtl {
+                    scrollable::Anchor::End
+                } else {
+                    scrollable::Anchor::Start
+                })
+                .width(Length::Fill)
+                .into(),
+        )
+    }
+
+    // Shift adds to the selection, otherwise we are done
+    fn pick(&mut self, glyph: &'static str) -> Task<BmojiMessage> {
+        self.selection.push(glyph);
+        if self.modifiers.shift() {
+            self.variant_picker = None;
+            focus(self.search_input_id.clone())
+        } else {
+            self.copy_selection_and_quit()
+        }
+    }
+
+    fn copy_selection_and_quit(&mut self) -> Task<BmojiMessage> {
+        for glyph in &self.selection {
+            self.options.history.add(glyph.to_string());
+        }
         Task::batch([
-            iced::clipboard::write(glyph.to_string()),
+            iced::clipboard::write(self.selection.concat()),
             self.save_and_quit(),
         ])
     }
 
+    // Searches once typing pauses for a bit, results of older queries are
+    // only kept in the cache
+    fn search(&mut self) -> Task<BmojiMessage> {
+        let generation = self.search_generation.fetch_add(1, Ordering::Relaxed) + 1;
+        if self.search_query.is_empty() {
+            self.search_results = None;
+            return Task::none();
+        }
+        if let Some(results) = self.search_cache.get(&self.search_key()) {
+            self.search_results = Some(results.clone());
+            return Task::none();
+        }
+
+        // Whatever was found for the previous query must not be picked with Enter
+        self.search_results = None;
+        *self.first_emoji.borrow_mut() = None;
+        let latest = self.search_generation.clone();
+        let key = self.search_key();
+        let scope = self.search_scope();
+        let search_eng = self.search_eng.clone();
+        Task::future(async move {
+            tokio::time::sleep(Duration::from_millis(conf::SEARCH_DEBOUNCE_MS)).await;
+            if latest.load(Ordering::Relaxed) != generation {
+                return None;
+            }
+
+            // Searching takes a while, it gets a thread of its own
+            tokio::task::spawn_blocking(move || {
+                let search_eng: &dyn SearchEngine = match &search_eng {
+                    Some(search_eng) => search_eng.as_ref(),
+                    None => &NameSearch,
+                };
+                let results = search_eng.search_emojis(&key.1, &scope, conf::SEARCH_PAGE_SIZE);
+                (key, results)
+            })
+            .await
+            .ok()
+        })
+        .and_then(move |(key, results)| {
+            Task::done(BmojiMessage::SearchDone(generation, key, results))
+        })
+    }
+
+    // Next page of the current query, once the end of the grid is close.
+    // Everything it found is already at hand, the search isn't run again
+    fn load_more(&mut self) {
+        let key = self.search_key();
+        if let Some(results) = self.search_results.as_mut() {
+            if results.show_more(conf::SEARCH_PAGE_SIZE) {
+                self.search_cache.insert(key, results.clone());
+            }
+        }
+    }
+
+    fn search_key(&self) -> SearchKey {
+        (
+            self.scoped.then_some(self.category),
+            self.search_query.clone(),
+        )
+    }
+
+    fn search_scope(&self) -> Scope {
+        match (self.scoped, self.category.group()) {
+            (false, _) => Scope::All,
+            (true, Some(group)) => Scope::Group(group),
+            (true, None) => Scope::Glyphs(
+                self.options
+                    .history
+                    .emojis()
+                    .into_iter()
+                    .map(|e| variants::untoned(e).glyph)
+                    .collect(),
+            ),
+        }
+    }
+
     fn save_and_quit(&self) -> Task<BmojiMessage> {
         self.options.save();
         window::latest().and_then(window::close)
@@ -303,7 +908,8 @@ impl Bmoji {
     fn new() -> (Self, Task<BmojiMessage>) {
         let res = Self::default();
         let id = res.search_input_id.clone();
-        (res, focus(id))
+        let load_search = load_search(res.langs.clone());
+        (res, Task::batch([focus(id), load_search]))
     }
 
     fn update(&mut self, message: BmojiMessage) -> iced::Task<BmojiMessage> {
@@ -311,32 +917,184 @@ impl Bmoji {
             BmojiMessage::Search(query) => {
                 self.search_query = query;
                 self.variant_picker = None;
+                self.lang_picker = false;
                 self.has_been_interacted = true;
-                focus(self.search_input_id.clone())
+                self.grid_offset = 0.0;
+                Task::batch([
+                    self.search(),
+                    scroll_to(self.grid_id.clone(), AbsoluteOffset { x: 0.0, y: 0.0 }),
+                    focus(self.search_input_id.clone()),
+                ])
+            }
+            BmojiMessage::Glyph(glyph) => {
+                // Modifier-click still lets us pick a variant other than the preferred one
+                match emoji::lookup_by_glyph::lookup(glyph) {
+                    Some(emoji) if self.modifiers.control() && variants::has_variants(emoji) => {
+                        self.has_been_interacted = true;
+                        self.variant_picker = Some(VariantPicker::new(variants::untoned(emoji)));
+                        Task::none()
+                    }
+                    _ => self.pick(glyph),
+                }
+            }
+            BmojiMessage::GlyphPressed(emoji) => {
+                self.has_been_interacted = true;
+                let since = Instant::now();
+                self.pressed = Some((emoji, since));
+                // The variants show up while the press is still held
+                Task::future(tokio::time::sleep(Duration::from_millis(
+                    conf::LONG_PRESS_MS,
+                )))
+                .map(move |_| BmojiMessage::LongPress(since))
             }
-            BmojiMessage::Glyph(glyph) => self.copy_and_quit(glyph),
+            BmojiMessage::LongPress(since) => match self.pressed {
+                Some((emoji, pressed_since)) if pressed_since == since => {
+                    self.pressed = None;
+                    self.variant_picker = Some(VariantPicker::new(emoji));
+                    Task::none()
+                }
+                _ => Task::none(),
+            },
+            // Released before it was long enough, or after the variants showed up
+            BmojiMessage::GlyphReleased => match self.pressed.take() {
+                Some((emoji, _)) => self.update(BmojiMessage::Glyph(
+                    variants::preferred(emoji, self.options.preference).glyph,
+                )),
+                None => Task::none(),
+            },
             BmojiMessage::ShowGlyphVariants(emoji) => {
                 self.has_been_interacted = true;
-                self.variant_picker = Some(VariantPicker { emoji });
+                self.variant_picker = Some(VariantPicker::new(emoji));
+                Task::none()
+            }
+            BmojiMessage::VariantChosen(emoji) => {
+                let preference = &mut self.options.preference;
+                if variants::family_of(emoji).is_some() {
+                    match (variants::is_toned(emoji), variants::tone_of(emoji)) {
+                        (false, _) => preference.skin_tone = None,
+                        (true, Some(tone)) => preference.skin_tone = Some(tone),
+                        // Mixed tones say nothing about which one is ours
+                        (true, None) => {}
+                    }
+                }
+                if let Some(gender) = variants::gender_of(emoji) {
+                    preference.gender = Some(gender);
+                }
+                self.pick(emoji.glyph)
+            }
+            BmojiMessage::PreviewVariant(emoji) => {
+                if let Some(variant_picker) = self.variant_picker.as_mut() {
+                    variant_picker.preview = Some(emoji);
+                }
+                Task::none()
+            }
+            BmojiMessage::ModifiersChanged(modifiers) => {
+                self.modifiers = modifiers;
                 Task::none()
             }
             BmojiMessage::CategoryChanged(category) => {
                 self.category = category;
+                self.subgroup = None;
                 self.variant_picker = None;
+                self.lang_picker = false;
                 self.has_been_interacted = true;
                 self.search_query = String::new();
-                focus(self.search_input_id.clone())
+                self.search_results = None;
+                let y = self
+                    .category_offsets()
+                    .iter()
+                    .find(|(c, _)| *c == category)
+                    .map_or(0.0, |(_, y)| *y);
+                self.grid_offset = y;
+                Task::batch([
+                    scroll_to(self.grid_id.clone(), AbsoluteOffset { x: 0.0, y }),
+                    focus(self.search_input_id.clone()),
+                ])
+            }
+            BmojiMessage::SubgroupToggled(subgroup) => {
+                self.has_been_interacted = true;
+                self.subgroup = if self.subgroup == Some(subgroup) {
+                    None
+                } else {
+                    Some(subgroup)
+                };
+                self.grid_offset = 0.0;
+                Task::batch([
+                    scroll_to(self.grid_id.clone(), AbsoluteOffset { x: 0.0, y: 0.0 }),
+                    focus(self.search_input_id.clone()),
+                ])
+            }
+            BmojiMessage::SearchReady(langs, search_eng) => {
+                // Another language was chosen while these were loading
+                if langs != self.langs {
+                    return Task::none();
+                }
+                // Whatever was found by name is worse than what the indexes find
+                self.search_eng = Some(search_eng);
+                self.search_cache.clear();
+                self.search()
+            }
+            BmojiMessage::SearchDone(generation, key, results) => {
+                // Typed over, or searched before the indexes were ready and
+                // the cache was cleared
+                if generation != self.search_generation.load(Ordering::Relaxed) {
+                    return Task::none();
+                }
+                if key == self.search_key() {
+                    self.search_results = Some(results.clone());
+                }
+                self.search_cache.insert(key, results);
+                Task::none()
+            }
+            BmojiMessage::ScopeToggled => {
+                self.scoped = !self.scoped;
+                self.has_been_interacted = true;
+                self.grid_offset = 0.0;
+                Task::batch([
+                    self.search(),
+                    scroll_to(self.grid_id.clone(), AbsoluteOffset { x: 0.0, y: 0.0 }),
+                    focus(self.search_input_id.clone()),
+                ])
+            }
+            BmojiMessage::GridScrolled(viewport) => {
+                self.grid_offset = viewport.absolute_offset().y;
+                if !self.search_query.is_empty() {
+                    let end = viewport.content_bounds().height - viewport.bounds().height;
+                    if self.grid_offset >= end - conf::LOAD_MORE_MARGIN {
+                        self.load_more();
+                    }
+                    return Task::none();
+                }
+
+                // The category bar follows whatever section we are looking at
+                if self.variant_picker.is_none() && self.subgroup.is_none() {
+                    let offsets = self.category_offsets();
+                    let y = viewport.absolute_offset().y;
+                    let current = if viewport.relative_offset().y >= 1.0 {
+                        offsets.last()
+                    } else {
+                        offsets.iter().rev().find(|(_, start)| *start <= y + 1.0)
+                    }
+                    .map(|(category, _)| *category);
+                    drop(offsets);
+                    if let Some(category) = current {
+                        self.category = category;
+                    }
+                }
+                Task::none()
             }
             BmojiMessage::Quit => self.save_and_quit(),
             BmojiMessage::OnSearchEnter => {
                 // Needed so that the borrow is dropped and we don't have two borrows at the same time
-                let fm = self.first_emoji.borrow().clone();
+                let fm = *self.first_emoji.borrow();
                 self.has_been_interacted = true;
-                if let Some(first_emoji) = fm {
+                if !self.selection.is_empty() && !self.modifiers.shift() {
+                    self.copy_selection_and_quit()
+                } else if let Some(first_emoji) = fm {
                     if first_emoji.variants.is_empty() {
-                        self.copy_and_quit(first_emoji.glyph)
+                        self.pick(first_emoji.glyph)
                     } else {
-                        self.variant_picker = Some(VariantPicker { emoji: first_emoji });
+                        self.variant_picker = Some(VariantPicker::new(first_emoji));
                         Task::none()
                     }
                 } else {
@@ -349,7 +1107,10 @@ impl Bmoji {
                 focus(self.search_input_id.clone())
             }
             BmojiMessage::OnUnfocused => {
-                if self.has_been_interacted {
+                // Whatever was selected is still wanted, it's copied on the way out
+                if !self.selection.is_empty() {
+                    self.copy_selection_and_quit()
+                } else if self.has_been_interacted {
                     self.save_and_quit()
                 } else {
                     Task::none()
@@ -360,11 +1121,43 @@ impl Bmoji {
                 Task::none()
             }
             BmojiMessage::GainFocus => window::latest().and_then(window::gain_focus),
+            BmojiMessage::CopySelection => self.copy_selection_and_quit(),
+            BmojiMessage::LanguagePickerToggled => {
+                self.has_been_interacted = true;
+                self.lang_picker = !self.lang_picker;
+                self.variant_picker = None;
+                Task::none()
+            }
+            BmojiMessage::LanguageChosen(lang) => {
+                self.lang_picker = false;
+                if self.langs.first().is_some_and(|first| first == lang) {
+                    return focus(self.search_input_id.clone());
+                }
+
+                // The chosen language goes first and replaces the one chosen
+                // before, the others are still searched in
+                self.options.chosen_language = Some(lang.to_string());
+                self.langs = get_langs(Some(lang), &self.options.languages);
+                self.texts = Texts::new(&self.langs);
+                // Names are searched until the indexes of the new languages are ready
+                self.search_eng = None;
+                self.search_cache.clear();
+                Task::batch([
+                    load_search(self.langs.clone()),
+                    self.search(),
+                    focus(self.search_input_id.clone()),
+                ])
+            }
+            BmojiMessage::ClearSelection => {
+                self.selection.clear();
+                focus(self.search_input_id.clone())
+            }
         }
     }
 
     fn view(&self) -> Element<'_, BmojiMessage, RoundedTheme> {
-        let inp_search = text_input("Search...", &self.search_query)
+        let rtl = self.texts.is_rtl();
+        let inp_search = text_input(&self.texts.get("search-placeholder"), &self.search_query)
             .on_input(BmojiMessage::Search)
             .on_submit(BmojiMessage::OnSearchEnter)
             .id(self.search_input_id.clone())
@@ -373,8 +1166,13 @@ impl Bmoji {
                 code_point: '🔎',
                 size: Some(Pixels(16.0)),
                 spacing: 10.0,
-                side: text_input::Side::Left,
+                side: if rtl {
+                    text_input::Side::Right
+                } else {
+                    text_input::Side::Left
+                },
             })
+            .align_x(reading_start(rtl))
             .line_height(1.2)
             .padding(6);
 
@@ -391,67 +1189,151 @@ impl Bmoji {
         .height(32)
         .width(32)
         .class(ButtonStyle::ClearSearch);
-        let search_row = row![inp_search, clear_search].spacing(7).padding(9);
+        // Shows the category searches would be restricted to
+        let scope_toggle = button(
+            text(self.category.glyph())
+                .font(EMOJI_FONT)
+                .align_x(alignment::Horizontal::Center)
+                .align_y(alignment::Vertical::Center),
+        )
+        .on_press(BmojiMessage::ScopeToggled)
+        .height(32)
+        .width(32)
+        .class(if self.scoped {
+            ButtonStyle::Category
+        } else {
+            ButtonStyle::Plain
+        });
+        // Shows the language searched in first
+        let lang_toggle = button(
+            text(
+                self.langs[0]
+                    .split('_')
+                    .next()
+                    .unwrap_or_default()
+                    .to_uppercase(),
+            )
+            .size(12)
+            .align_x(alignment::Horizontal::Center)
+            .align_y(alignment::Vertical::Center),
+        )
+        .on_press(BmojiMessage::LanguagePickerToggled)
+        .height(32)
+        .width(32)
+        .class(if self.lang_picker {
+            ButtonStyle::Category
+        } else {
+            ButtonStyle::Plain
+        });
+        let search_row = mirrored_row(
+            rtl,
+            [
+                inp_search.into(),
+                lang_toggle.into(),
+                scope_toggle.into(),
+                clear_search.into(),
+            ],
+        )
+        .spacing(7)
+        .padding(9);
 
-        fn emojis_category(cat: &str) -> Vec<&'static Emoji> {
-            emoji::lookup_by_glyph::iter_emoji()
-                .filter(|e| e.group == cat && !e.is_variant)
-                .collect()
-        }
+        let body: Element<'_, BmojiMessage, RoundedTheme> = if self.lang_picker {
+            *self.first_emoji.borrow_mut() = None;
+            let langs = emoji::ANNOTATION_LANGS_AVAILABLE.iter().map(|lang| {
+                button(
+                    text(i18n::native_name(lang))
+                        .width(Length::Fill)
+                        .align_x(reading_start(rtl)),
+                )
+                .on_press(BmojiMessage::LanguageChosen(lang))
+                .width(Length::Fill)
+                .class(if self.langs[0] == *lang {
+                    ButtonStyle::Category
+                } else {
+                    ButtonStyle::Plain
+                })
+                .into()
+            });
+            iced_aw::card(
+                text(self.texts.get("search-language")),
+                scrollable(column(langs).spacing(2)).height(Length::Fill),
+            )
+            .height(Length::Fill)
+            .into()
+        } else if let Some(variant_picker) = self.variant_picker.as_ref() {
+            let family = variants::family_of(variant_picker.emoji);
+            let gender_group = variants::gender_group_of(variant_picker.emoji);
+            let choices: Element<'_, BmojiMessage, RoundedTheme> = match (family, gender_group) {
+                (_, Some(group)) => {
+                    *self.first_emoji.borrow_mut() = Some(group.lead());
+                    gender_grid(group, rtl)
+                }
+                (Some(family), _) if family.persons == 2 => {
+                    *self.first_emoji.borrow_mut() = Some(family.base);
+                    tones_grid(family, rtl)
+                }
+                (_, None) => {
+                    let choices = match family {
+                        Some(family) => family.members(),
+                        None => variant_picker.emoji.variants.iter().collect(),
+                    };
+                    *self.first_emoji.borrow_mut() = choices.first().copied();
 
-        let body: Element<'_, BmojiMessage, RoundedTheme> = if let Some(variant_picker) =
-            self.variant_picker.as_ref()
-        {
-            *self.first_emoji.borrow_mut() = Some(variant_picker.emoji.variants.first().unwrap());
+                    let choices = choices.into_iter().map(|v| {
+                        emoji_button(v.glyph, false)
+                            .on_press(BmojiMessage::VariantChosen(v))
+                            .into()
+                    });
+                    container(mirrored_row(rtl, choices).spacing(7))
+                        .width(Length::Fill)
+                        .align_x(reading_start(rtl))
+                        .into()
+                }
+            };
 
+            let header = variant_picker.preview.unwrap_or(variant_picker.emoji);
             iced_aw::card(
-                text(variant_picker.emoji.glyph).font(EMOJI_FONT),
-                container(
-                    row(variant_picker
-                        .emoji
-                        .variants
-                        .iter()
-                        .map(|v| {
-                            emoji_button(v.glyph, false)
-                                .on_press(BmojiMessage::Glyph(v.glyph))
-                                .into()
-                        })
-                        .collect::<Vec<_>>())
-                    .spacing(7),
-                )
-                .height(Length::Fill),
+                text(header.glyph).font(EMOJI_FONT),
+                container(choices).height(Length::Fill),
             )
             .close_size(conf::EMOJI_SIZE as f32)
             .height(Length::Fill)
             .into()
         } else {
-            let emoji_list = if self.search_query.is_empty() {
-                match self.category {
-                    EmojiCategory::History => self.options.history.emojis(),
-                    EmojiCategory::Activities => emojis_category("Activities"),
-                    EmojiCategory::AnimalsAndNature => emojis_category("Animals & Nature"),
-                    EmojiCategory::Flags => emojis_category("Flags"),
-                    EmojiCategory::FoodAndDrink => emojis_category("Food & Drink"),
-                    EmojiCategory::Objects => emojis_category("Objects"),
-                    EmojiCategory::PeopleAndBody => emojis_category("People & Body"),
-                    EmojiCategory::SmileysAndEmotion => emojis_category("Smileys & Emotion"),
-                    EmojiCategory::Symbols => emojis_category("Symbols"),
-                    EmojiCategory::TravelAndPlaces => emojis_category("Travel & Places"),
-                }
+            let sections = if self.search_query.is_empty() {
+                self.category_sections()
             } else {
-                self.search_eng
-                    .search_emojis(&self.search_query, conf::EMOJI_PER_LINE * 3)
-            }
-            .into_iter()
-            .filter(|_| true)
-            .collect::<Vec<_>>();
-            *self.first_emoji.borrow_mut() = emoji_list.first().cloned();
+                let mut results = Vec::new();
+                for emoji in self
+                    .search_results
+                    .iter()
+                    .flat_map(|r| r.emojis().iter().copied())
+                {
+                    let emoji = variants::preferred(emoji, self.options.preference);
+                    if !results.contains(&emoji) {
+                        results.push(emoji);
+                    }
+                }
+                vec![Section {
+                    category: None,
+                    emojis: Cow::Owned(results),
+                }]
+            };
+            *self.first_emoji.borrow_mut() = sections
+                .iter()
+                .find(|s| s.category.is_none() || s.category == Some(self.category))
+                .or(sections.first())
+                .and_then(|s| s.emojis.first().copied())
+                // As shown in the grid, in the tone and gender preferred
+                .map(|e| variants::preferred(e, self.options.preference));
 
-            if emoji_list.is_empty() {
+            if sections.iter().all(|s| s.emojis.is_empty()) {
                 let msg = if self.search_query.is_empty() {
-                    "Use emojis for them to appear here"
+                    self.texts.get("history-empty")
+                } else if self.search_results.is_none() {
+                    self.texts.get("searching")
                 } else {
-                    "Nothing found"
+                    self.texts.get("nothing-found")
                 };
 
                 let txt: Element<'_, BmojiMessage, RoundedTheme> = iced::widget::Text::new(msg)
@@ -464,18 +1346,36 @@ impl Bmoji {
                     .width(Length::Fill)
                     .height(Length::Fill)
                     .into()
+            } else if let Some(results) = &self.search_results {
+                let mut args = FluentArgs::new();
+                args.set("shown", results.emojis().len());
+                args.set("total", results.total());
+                let count = if results.emojis().len() < results.total() {
+                    self.texts.get_with("results-shown", &args)
+                } else {
+                    self.texts.get_with("results", &args)
+                };
+                column![
+                    text(count)
+                        .size(12)
+                        .width(Length::Fill)
+                        .align_x(reading_start(rtl))
+                        .class(TextType::Disabled),
+                    self.grid_of(sections)
+                ]
+                .spacing(conf::SPACING)
+                .into()
             } else {
-                self.grid_of(emoji_list)
+                self.grid_of(sections)
             }
         };
 
         fn category_btn<'a>(
-            glyph: &'static str,
             current_cat: EmojiCategory,
             category: EmojiCategory,
         ) -> iced::widget::Button<'a, BmojiMessage, RoundedTheme> {
             button(
-                text(glyph)
+                text(category.glyph())
                     .font(EMOJI_FONT)
                     .size(conf::CAT_EMOJI_FONT_SIZE)
                     .align_x(alignment::Horizontal::Center)
@@ -491,12 +1391,10 @@ impl Bmoji {
         }
 
         fn category<'a>(
-            glyph: &'static str,
             current_cat: EmojiCategory,
             category: EmojiCategory,
         ) -> iced::widget::Button<'a, BmojiMessage, RoundedTheme> {
-            category_btn(glyph, current_cat, category)
-                .on_press(BmojiMessage::CategoryChanged(category))
+            category_btn(current_cat, category).on_press(BmojiMessage::CategoryChanged(category))
         }
 
         let history_on_press = if self.options.history.is_empty() {
@@ -505,18 +1403,22 @@ impl Bmoji {
             Some(BmojiMessage::CategoryChanged(EmojiCategory::History))
         };
 
-        let categories = row!(
-            category_btn("🕑", self.category, EmojiCategory::History)
-                .on_press_maybe(history_on_press),
-            category("😃", self.category, EmojiCategory::SmileysAndEmotion),
-            category("🧑", self.category, EmojiCategory::PeopleAndBody),
-            category("⚽", self.category, EmojiCategory::Activities),
-            category("🐻", self.category, EmojiCategory::AnimalsAndNature),
-            category("🎌", self.category, EmojiCategory::Flags),
-            category("🍔", self.category, EmojiCategory::FoodAndDrink),
-            category("💡", self.category, EmojiCategory::Objects),
-            category("💕", self.category, EmojiCategory::Symbols),
-            category("🚀", self.category, EmojiCategory::TravelAndPlaces),
+        let categories = mirrored_row(
+            rtl,
+            [
+                category_btn(self.category, EmojiCategory::History)
+                    .on_press_maybe(history_on_press)
+                    .into(),
+                category(self.category, EmojiCategory::SmileysAndEmotion).into(),
+                category(self.category, EmojiCategory::PeopleAndBody).into(),
+                category(self.category, EmojiCategory::Activities).into(),
+                category(self.category, EmojiCategory::AnimalsAndNature).into(),
+                category(self.category, EmojiCategory::Flags).into(),
+                category(self.category, EmojiCategory::FoodAndDrink).into(),
+                category(self.category, EmojiCategory::Objects).into(),
+                category(self.category, EmojiCategory::Symbols).into(),
+                category(self.category, EmojiCategory::TravelAndPlaces).into(),
+            ],
         )
         .spacing(0)
         .padding(0)
@@ -524,7 +1426,41 @@ impl Bmoji {
         .width(Length::Fill)
         .height(30);
 
-        container(column![search_row, body, categories].spacing(2))
+        let selection_row = (!self.selection.is_empty()).then(|| {
+            mirrored_row(
+                rtl,
+                [
+                    text(self.selection.concat())
+                        .font(EMOJI_FONT)
+                        .size(conf::CAT_EMOJI_FONT_SIZE)
+                        .width(Length::Fill)
+                        .align_x(reading_start(rtl))
+                        .into(),
+                    button(text(self.texts.get("copy")).align_y(alignment::Vertical::Center))
+                        .on_press(BmojiMessage::CopySelection)
+                        .height(32)
+                        .class(ButtonStyle::ClearSearch)
+                        .into(),
+                    button(
+                        text("X")
+                            .align_x(alignment::Horizontal::Center)
+                            .align_y(alignment::Vertical::Center),
+                    )
+                    .on_press(BmojiMessage::ClearSelection)
+                    .height(32)
+                    .width(32)
+                    .class(ButtonStyle::ClearSearch)
+                    .into(),
+                ],
+            )
+            .spacing(7)
+            .padding([0, 9])
+            .align_y(alignment::Vertical::Center)
+        });
+
+        let chips = self.subgroup_chips();
+
+        container(column![search_row, chips, selection_row, body, categories].spacing(2))
             .padding([VER_PADDING as u16, HOR_PADDING as u16])
             .into()
     }
@@ -553,8 +1489,10 @@ impl Bmoji {
             Event::Mouse(mouse::Event::CursorEntered) => Some(BmojiMessage::GainFocus),
 
             // Avoid treating them as interactions
-            Event::Mouse(mouse::Event::CursorMoved { .. })
-            | Event::Keyboard(keyboard::Event::ModifiersChanged(_)) => None,
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                Some(BmojiMessage::ModifiersChanged(modifiers))
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => None,
 
             // Most events are treated as simple interactions, showing the user has some interest
             Event::Window(window::Event::Unfocused) => Some(BmojiMessage::OnUnfocused),
@@ -566,38 +1504,247 @@ impl Bmoji {
     }
 }
 
-fn get_langs() -> Vec<String> {
+// Indexes are opened in the background, as many as languages there are.
+// That's disk work, so it gets a thread of its own instead of holding the executor
+fn load_search(langs: Vec<String>) -> Task<BmojiMessage> {
+    Task::future(tokio::task::spawn_blocking(move || {
+        let langs_ref = langs.iter().map(|l| l.as_str()).collect::<Vec<&str>>();
+        let search_eng = TantivySearch::new(&langs_ref, &KEYWORDS_PATH, &USER_INDEX_PATH);
+        (langs, Arc::new(search_eng))
+    }))
+    .then(|loaded| match loaded {
+        Ok((langs, search_eng)) => Task::done(BmojiMessage::SearchReady(langs, search_eng)),
+        // Names are still searched without the indexes
+        Err(err) => {
+            eprintln!("Couldn't load the search indexes: {err}");
+            Task::none()
+        }
+    })
+}
+
+/// Language of a POSIX locale like "sr_RS.UTF-8@latin", as "sr-Latn-RS".
+/// None for the "C" and "POSIX" locales, which have no language at all
+fn posix_lang(locale: &str) -> Option<String> {
+    let (rest, modifier) = locale.split_once('@').unwrap_or((locale, ""));
+    let lang = rest.split('.').next().unwrap_or(rest);
+    if lang.is_empty() || lang == "C" || lang == "POSIX" {
+        return None;
+    }
+
+    let (language, territory) = lang.split_once('_').unwrap_or((lang, ""));
+    let script = match modifier {
+        "latin" => "Latn",
+        "cyrillic" => "Cyrl",
+        "devanagari" => "Deva",
+        _ => "",
+    };
+    Some(
+        [language, script, territory]
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("-"),
+    )
+}
+
+/// Languages asked for by the environment, most preferred first
+fn env_langs() -> Vec<String> {
+    langs_of(|name| env::var(name).ok())
+}
+
+/// Languages asked for by some variables, in the order gettext follows:
+/// LANGUAGE, a list separated by colons, and then the locale (LC_ALL,
+/// LC_MESSAGES or LANG, the first one set)
+fn langs_of(var: impl Fn(&str) -> Option<String>) -> Vec<String> {
+    let var = |name| var(name).filter(|v| !v.is_empty());
+    let locale = var("LC_ALL")
+        .or_else(|| var("LC_MESSAGES"))
+        .or_else(|| var("LANG"));
+    // Without one, or with "C", LANGUAGE is ignored too
+    let Some(locale_lang) = locale.as_deref().and_then(posix_lang) else {
+        return Vec::new();
+    };
+
+    let language = var("LANGUAGE").unwrap_or_default();
+    let mut langs = language
+        .split(':')
+        .filter_map(posix_lang)
+        .collect::<Vec<_>>();
+    langs.push(locale_lang);
+    langs
+}
+
+/// Languages with annotations to search in: the chosen one, then the
+/// configured ones if any, otherwise those of the environment and English
+fn get_langs(chosen: Option<&str>, configured: &[String]) -> Vec<String> {
     use emoji::ANNOTATION_LANGS_AVAILABLE;
     use fluent_langneg::{convert_vec_str_to_langids, negotiate};
 
     const DEFAULT_LANG: &str = "en";
-    const UTF8_SUFFIX: &str = ".UTF-8";
 
-    let lang_str = std::env::var("LANG")
-        .map(|s| {
-            if s.ends_with(UTF8_SUFFIX) {
-                s[0..s.len() - UTF8_SUFFIX.len()].to_string()
-            } else {
-                s
+    let mut requested = chosen.map(str::to_string).into_iter().collect::<Vec<_>>();
+    if configured.is_empty() {
+        requested.extend(env_langs());
+        requested.push(DEFAULT_LANG.to_string());
+    } else {
+        requested.extend_from_slice(configured);
+    }
+
+    let available = convert_vec_str_to_langids(ANNOTATION_LANGS_AVAILABLE).unwrap();
+    let mut langs: Vec<String> = Vec::new();
+    for requested in requested {
+        let Ok(lang) = requested.parse::<fluent_langneg::LanguageIdentifier>() else {
+            continue;
+        };
+        let negotiated = negotiate::negotiate_languages(
+            &[&lang],
+            &available,
+            None,
+            negotiate::NegotiationStrategy::Filtering,
+        );
+        // Plain "sr" fits "sr-Latn-RS" too, the one sharing the most is preferred
+        let best = negotiated.iter().min_by_key(|candidate| {
+            std::cmp::Reverse(
+                (candidate.script == lang.script) as u8 + (candidate.region == lang.region) as u8,
+            )
+        });
+        // The name of the annotations, "es_MX" rather than "es-MX"
+        let found = best
+            .and_then(|lang| available.iter().position(|a| a == *lang))
+            .map(|pos| ANNOTATION_LANGS_AVAILABLE[pos].to_string());
+        if let Some(found) = found {
+            if !langs.contains(&found) {
+                langs.push(found);
             }
+        }
+    }
+
+    if langs.is_empty() {
+        langs.push(DEFAULT_LANG.to_string());
+    }
+    langs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Height of a column with the spaces and lines, as the grid lays it out
+    fn laid_out(top: f32, lines: &[GridLine<'_>], bottom: f32) -> Vec<f32> {
+        [top]
+            .into_iter()
+            .chain(lines.iter().map(GridLine::height))
+            .chain([bottom])
+            .filter(|height| *height > 0.0)
+            .collect()
+    }
+
+    fn column_height(heights: &[f32]) -> f32 {
+        heights.iter().sum::<f32>() + conf::SPACING as f32 * (heights.len() - 1) as f32
+    }
+
+    #[test]
+    fn visible_lines_keep_the_grid_in_place() {
+        let all = || (0..200).map(|_| GridLine::Header(EmojiCategory::Flags));
+        let total = column_height(&all().map(|l| l.height()).collect::<Vec<_>>());
+        let step = (conf::SECTION_HEADER_SIZE + conf::SPACING) as f32;
+
+        for offset in [0.0, 10.0, step * 3.0, step * 50.0, total - 100.0] {
+            let (top, visible, bottom) = visible_lines(all().collect(), offset, 100.0);
+            let heights = laid_out(top, &visible, bottom);
+            assert_eq!(column_height(&heights), total, "offset {offset}");
+
+            // The first line built starts where it would with every line there
+            let hidden = (top / step).round();
+            let first_line = if top > 0.0 {
+                top + conf::SPACING as f32
+            } else {
+                0.0
+            };
+            assert_eq!(first_line, hidden * step, "offset {offset}");
+        }
+    }
+
+    #[test]
+    fn visible_lines_at_the_top_have_no_space_above() {
+        let lines = (0..200)
+            .map(|_| GridLine::Header(EmojiCategory::Flags))
+            .collect();
+        let (top, visible, bottom) = visible_lines(lines, 0.0, 100.0);
+        assert_eq!(top, 0.0);
+        assert!(!visible.is_empty());
+        assert!(bottom > 0.0);
+    }
+
+    #[test]
+    fn posix_locales_name_their_language() {
+        let tests = [
+            ("C", None),
+            ("C.UTF-8", None),
+            ("POSIX", None),
+            ("", None),
+            ("en_US.utf8", Some("en-US")),
+            ("de_DE@euro", Some("de-DE")),
+            ("es", Some("es")),
+            ("sr_RS.UTF-8@latin", Some("sr-Latn-RS")),
+        ];
+        for (locale, lang) in tests {
+            assert_eq!(posix_lang(locale).as_deref(), lang, "{locale}");
+        }
+    }
+
+    type Vars<'a> = &'a [(&'a str, &'a str)];
+
+    fn langs_with(vars: Vars<'_>) -> Vec<String> {
+        langs_of(|name| {
+            vars.iter()
+                .find(|(var, _)| *var == name)
+                .map(|(_, value)| value.to_string())
         })
-        .unwrap_or(DEFAULT_LANG.to_string());
+    }
 
-    let def_lang: fluent_langneg::LanguageIdentifier =
-        fluent_langneg::LanguageIdentifier::from_str("en").expect("Devel error");
-    let lang: fluent_langneg::LanguageIdentifier = lang_str.parse().unwrap();
-    let available = convert_vec_str_to_langids(ANNOTATION_LANGS_AVAILABLE).unwrap();
+    #[test]
+    fn environment_languages_follow_gettext() {
+        let tests: &[(Vars<'_>, &[&str])] = &[
+            (&[("LANG", "en_US.utf8")], &["en-US"]),
+            (
+                &[
+                    ("LC_ALL", "de_DE@euro"),
+                    ("LC_MESSAGES", "fr_FR"),
+                    ("LANG", "en_US"),
+                ],
+                &["de-DE"],
+            ),
+            (&[("LC_MESSAGES", "fr_FR"), ("LANG", "en_US")], &["fr-FR"]),
+            (&[("LC_ALL", ""), ("LANG", "en_US")], &["en-US"]),
+            (
+                &[("LANG", "en_US"), ("LANGUAGE", "es:pt_BR")],
+                &["es", "pt-BR", "en-US"],
+            ),
+            (
+                &[("LC_ALL", "C"), ("LANG", "en_US"), ("LANGUAGE", "es")],
+                &[],
+            ),
+            (&[("LANG", "C.UTF-8"), ("LANGUAGE", "es")], &[]),
+            (&[("LANGUAGE", "es")], &[]),
+        ];
+        for (vars, langs) in tests {
+            assert_eq!(langs_with(vars), *langs, "{vars:?}");
+        }
+    }
+
+    #[test]
+    fn configured_languages_are_the_whole_list() {
+        let configured = |langs: &[&str]| langs.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+        assert_eq!(get_langs(None, &configured(&["de"])), ["de"]);
+        assert_eq!(get_langs(None, &configured(&["ca", "es"])), ["ca", "es"]);
+        assert_eq!(get_langs(None, &configured(&["es-MX", "xx"])), ["es_MX"]);
+    }
 
-    let negotation = negotiate::negotiate_languages(
-        &[lang],
-        &available,
-        Some(&def_lang),
-        negotiate::NegotiationStrategy::Matching,
-    );
-
-    [
-        negotation.get(0).unwrap().to_string(),
-        DEFAULT_LANG.to_string(),
-    ]
-    .to_vec()
+    #[test]
+    fn the_chosen_language_goes_first() {
+        let configured = ["ca".to_string(), "es".to_string()];
+        assert_eq!(get_langs(Some("fr"), &configured), ["fr", "ca", "es"]);
+        assert_eq!(get_langs(Some("es"), &configured), ["es", "ca"]);
+    }
 }
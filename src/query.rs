@@ -0,0 +1,214 @@
+use std::ops::{Bound, RangeBounds};
+
+use emoji::Emoji;
+
+use crate::emoticons;
+use crate::index;
+use crate::variants::SkinTone;
+
+/// A condition on the emojis found, besides matching the words
+#[derive(Debug, Clone, PartialEq)]
+pub enum Filter {
+    Group(String),
+    Subgroup(String),
+    /// Unicode version the emoji was introduced in
+    Version(Bound<f64>, Bound<f64>),
+    Variant(bool),
+    /// Emojis with this keyword are left out
+    Exclude(String),
+}
+
+impl Filter {
+    fn parse(key: &str, value: &str) -> Option<Self> {
+        let value = value.to_lowercase();
+        match key.to_lowercase().as_str() {
+            "group" => Some(Filter::Group(value)),
+            "subgroup" => Some(Filter::Subgroup(value)),
+            "version" => {
+                let (lower, upper) = if let Some(v) = value.strip_prefix("<=") {
+                    (Bound::Unbounded, Bound::Included(v.parse().ok()?))
+                } else if let Some(v) = value.strip_prefix(">=") {
+                    (Bound::Included(v.parse().ok()?), Bound::Unbounded)
+                } else if let Some(v) = value.strip_prefix('<') {
+                    (Bound::Unbounded, Bound::Excluded(v.parse().ok()?))
+                } else if let Some(v) = value.strip_prefix('>') {
+                    (Bound::Excluded(v.parse().ok()?), Bound::Unbounded)
+                } else {
+                    let v = value.strip_prefix('=').unwrap_or(&value).parse().ok()?;
+                    (Bound::Included(v), Bound::Included(v))
+                };
+                Some(Filter::Version(lower, upper))
+            }
+            "variant" => match value.as_str() {
+                "yes" | "true" => Some(Filter::Variant(true)),
+                "no" | "false" => Some(Filter::Variant(false)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Same as the indexes do, for when there are none
+    pub fn matches(&self, emoji: &Emoji) -> bool {
+        match self {
+            Filter::Group(group) => emoji.group.to_lowercase().contains(group),
+            Filter::Subgroup(subgroup) => emoji.subgroup.contains(subgroup),
+            Filter::Version(lower, upper) => (*lower, *upper).contains(&index::version_of(emoji)),
+            Filter::Variant(is_variant) => emoji.is_variant == *is_variant,
+            Filter::Exclude(keyword) => !emoji.name.contains(keyword),
+        }
+    }
+}
+
+/// A search as typed, with the filters taken out of it. Anything that can't
+/// be understood as a filter is searched as a word
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Query {
+    pub text: String,
+    pub filters: Vec<Filter>,
+    /// Found emojis are shown in this tone, those without tones are left out
+    pub tone: Option<SkinTone>,
+    /// Emojis typed as emoticons, they go before anything else found
+    pub emoticons: Vec<&'static Emoji>,
+}
+
+impl Query {
+    /// Understands `group:food`, `subgroup:flag`, `version:<=13`,
+    /// `variant:no`, `tone:medium`, `-keyword` and emoticons like `:)`
+    pub fn parse(query: &str) -> Self {
+        let mut parsed = Query::default();
+        let mut words = Vec::new();
+        for word in query.split_whitespace() {
+            if let Some(emoji) = emoticons::emoji_of(word) {
+                parsed.emoticons.push(emoji);
+                continue;
+            }
+            if let Some(keyword) = word.strip_prefix('-').filter(|k| !k.is_empty()) {
+                parsed.filters.push(Filter::Exclude(keyword.to_lowercase()));
+                continue;
+            }
+
+            let Some((key, value)) = word.split_once(':').filter(|(_, v)| !v.is_empty()) else {
+                words.push(word);
+                continue;
+            };
+            if key.eq_ignore_ascii_case("tone") {
+                let tone = SkinTone::from_qualifier(&format!("{} skin tone", value.to_lowercase()));
+                if tone.is_some() {
+                    parsed.tone = tone;
+                    continue;
+                }
+            } else if let Some(filter) = Filter::parse(key, value) {
+                parsed.filters.push(filter);
+                continue;
+            }
+            words.push(word);
+        }
+
+        parsed.text = words.join(" ");
+        parsed
+    }
+
+    /// Whether there's anything to search for besides the emoticons
+    pub fn is_only_emoticons(&self) -> bool {
+        !self.emoticons.is_empty() && self.text.is_empty() && self.filters.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use emoji::lookup_by_glyph::lookup;
+
+    use super::*;
+
+    fn version_filter(query: &str) -> Filter {
+        match Query::parse(query).filters.as_slice() {
+            [filter] => filter.clone(),
+            filters => panic!("{query:?} gave {filters:?}"),
+        }
+    }
+
+    #[test]
+    fn filters_are_taken_out_of_the_words() {
+        let tests = [
+            (
+                "cat group:Animals",
+                "cat",
+                vec![Filter::Group("animals".into())],
+            ),
+            ("subgroup:Flag", "", vec![Filter::Subgroup("flag".into())]),
+            (
+                "heart version:<=13",
+                "heart",
+                vec![Filter::Version(Bound::Unbounded, Bound::Included(13.0))],
+            ),
+            ("variant:no", "", vec![Filter::Variant(false)]),
+            ("variant:yes", "", vec![Filter::Variant(true)]),
+            ("face -Cat", "face", vec![Filter::Exclude("cat".into())]),
+            (
+                "-cat -dog",
+                "",
+                vec![Filter::Exclude("cat".into()), Filter::Exclude("dog".into())],
+            ),
+            // Not filters after all, searched as words
+            ("variant:maybe", "variant:maybe", vec![]),
+            ("version:new", "version:new", vec![]),
+            ("colour:red", "colour:red", vec![]),
+            ("group:", "group:", vec![]),
+            ("- cat", "- cat", vec![]),
+        ];
+        for (query, text, filters) in tests {
+            let parsed = Query::parse(query);
+            assert_eq!(parsed.text, text, "{query}");
+            assert_eq!(parsed.filters, filters, "{query}");
+        }
+    }
+
+    #[test]
+    fn tones_and_emoticons_are_not_words() {
+        let parsed = Query::parse(":) wave tone:dark");
+        assert_eq!(parsed.text, "wave");
+        assert_eq!(parsed.tone, Some(SkinTone::Dark));
+        assert_eq!(parsed.emoticons, vec![lookup("🙂").unwrap()]);
+
+        assert_eq!(Query::parse("tone:purple").text, "tone:purple");
+        assert!(Query::parse(":)").is_only_emoticons());
+        assert!(!Query::parse(":) group:smileys").is_only_emoticons());
+    }
+
+    #[test]
+    fn versions_match_as_written() {
+        let tests = [
+            ("version:13.1", Bound::Included(13.1), Bound::Included(13.1)),
+            ("version:<=12.1", Bound::Unbounded, Bound::Included(12.1)),
+            ("version:>=0.7", Bound::Included(0.7), Bound::Unbounded),
+        ];
+        for (query, lower, upper) in tests {
+            assert_eq!(
+                version_filter(query),
+                Filter::Version(lower, upper),
+                "{query}"
+            );
+        }
+
+        // Face with spiral eyes, 13.1; health worker, 12.1; frowning face, 0.7
+        let (spiral, health, frowning) = ("😵\u{200d}💫", "🧑\u{200d}⚕\u{fe0f}", "☹\u{fe0f}");
+        let tests = [
+            ("version:13.1", spiral, true),
+            ("version:13.1", health, false),
+            ("version:<=12.1", health, true),
+            ("version:<=12.1", spiral, false),
+            ("version:>=0.7", frowning, true),
+            ("version:>=0.7", spiral, true),
+            ("version:<0.7", frowning, false),
+        ];
+        for (query, glyph, matches) in tests {
+            let emoji = lookup(glyph).unwrap();
+            assert_eq!(
+                version_filter(query).matches(emoji),
+                matches,
+                "{query} {glyph}"
+            );
+        }
+    }
+}
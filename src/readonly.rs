@@ -0,0 +1,87 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use tantivy::directory::{
+    error::{DeleteError, LockError, OpenReadError, OpenWriteError},
+    Directory, DirectoryLock, FileHandle, Lock, MmapDirectory, WatchCallback, WatchHandle,
+    WritePtr,
+};
+
+/// Index that is only ever read, so opening it needs no lock files
+#[derive(Debug, Clone)]
+pub enum ReadOnlyDirectory {
+    /// Embedded in the binary, read right from it instead of being copied
+    /// into memory first
+    Embedded(&'static [(&'static str, &'static [u8])]),
+    /// Installed along with bmoji, somewhere we may not be able to write to
+    Installed(MmapDirectory),
+}
+
+impl ReadOnlyDirectory {
+    fn file(files: &[(&str, &'static [u8])], path: &Path) -> Result<&'static [u8], OpenReadError> {
+        files
+            .iter()
+            .find(|(name, _)| Path::new(name) == path)
+            .map(|(_, data)| *data)
+            .ok_or_else(|| OpenReadError::FileDoesNotExist(path.to_path_buf()))
+    }
+}
+
+fn read_only() -> io::Error {
+    io::Error::new(io::ErrorKind::PermissionDenied, "the index is read-only")
+}
+
+impl Directory for ReadOnlyDirectory {
+    fn get_file_handle(&self, path: &Path) -> Result<Arc<dyn FileHandle>, OpenReadError> {
+        match self {
+            Self::Embedded(files) => Ok(Arc::new(Self::file(files, path)?)),
+            Self::Installed(directory) => directory.get_file_handle(path),
+        }
+    }
+
+    fn delete(&self, path: &Path) -> Result<(), DeleteError> {
+        Err(DeleteError::IoError {
+            io_error: Arc::new(read_only()),
+            filepath: path.to_path_buf(),
+        })
+    }
+
+    fn exists(&self, path: &Path) -> Result<bool, OpenReadError> {
+        match self {
+            Self::Embedded(files) => Ok(Self::file(files, path).is_ok()),
+            Self::Installed(directory) => directory.exists(path),
+        }
+    }
+
+    fn open_write(&self, path: &Path) -> Result<WritePtr, OpenWriteError> {
+        Err(OpenWriteError::wrap_io_error(
+            read_only(),
+            PathBuf::from(path),
+        ))
+    }
+
+    fn atomic_read(&self, path: &Path) -> Result<Vec<u8>, OpenReadError> {
+        match self {
+            Self::Embedded(files) => Self::file(files, path).map(<[u8]>::to_vec),
+            Self::Installed(directory) => directory.atomic_read(path),
+        }
+    }
+
+    fn atomic_write(&self, _path: &Path, _data: &[u8]) -> io::Result<()> {
+        Err(read_only())
+    }
+
+    fn sync_directory(&self) -> io::Result<()> {
+        Ok(())
+    }
+
+    // Nobody can change the index, readers have nothing to wait for
+    fn acquire_lock(&self, _lock: &Lock) -> Result<DirectoryLock, LockError> {
+        Ok(DirectoryLock::from(Box::new(())))
+    }
+
+    fn watch(&self, _watch_callback: WatchCallback) -> tantivy::Result<WatchHandle> {
+        Ok(WatchHandle::empty())
+    }
+}
@@ -1,126 +1,570 @@
-use emoji::{lookup_by_glyph::iter_emoji, Emoji};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fs::{self, File};
+use std::io::BufReader;
+use std::path::Path;
+use std::sync::Arc;
+
+use emoji::{
+    lookup_by_glyph::{iter_emoji, lookup},
+    Emoji, Status,
+};
 use tantivy::{
     collector::TopDocs,
     directory::MmapDirectory,
-    doc,
-    query::QueryParser,
-    schema::{Field, Schema, Value, STORED, TEXT},
-    DocAddress, Index, Score, Searcher, TantivyDocument,
+    query::{AllQuery, BooleanQuery, BoostQuery, Occur, RangeQuery, TermQuery},
+    schema::{Field, IndexRecordOption, Value},
+    DocAddress, Index, ReloadPolicy, Score, Searcher, TantivyDocument, Term,
 };
 
-pub trait SearchEngine {
-    fn search_emojis(&self, emoji: &str, max_count: u32) -> Vec<&'static Emoji>;
-}
+use crate::groups;
+use crate::index;
+use crate::query::{Filter, Query};
+use crate::readonly::ReadOnlyDirectory;
+use crate::variants::{self, SkinTone};
 
-pub struct TantivySearch {
-    searcher: Searcher,
-    glyph: Field,
-    query_parser: QueryParser,
+// Generated by build.rs: the files of the index of every language
+mod prebuilt {
+    include!(concat!(env!("OUT_DIR"), "/indexes.rs"));
 }
 
-struct DataPair {
-    annotation: Field,
-    glyph: Field,
+// User keywords could be in any language, they are split in words
+const USER_LANG: &str = "";
+
+/// Where `bmoji --write-index` puts the indexes when installing, read-only
+pub const DATA_DIR: &str = match option_env!("BMOJI_DATA_DIR") {
+    Some(dir) => dir,
+    None => "/app/share/bmoji",
+};
+
+// How much more emojis with all the words searched are worth
+const ALL_WORDS_BOOST: Score = 2.0;
+// How much more words written exactly as typed, accents and all, are worth
+const EXACT_BOOST: Score = 1.5;
+// Words found only by their stem are worth less than those typed
+const STEMMED_BOOST: Score = 0.5;
+// How much less each language is worth than the one before it
+const LANG_BOOST_STEP: Score = 0.5;
+
+/// Everything a query found, best first, shown a page at a time
+#[derive(Debug, Clone, Default)]
+pub struct SearchResults {
+    // Shared by the copies kept in the cache
+    all: Arc<[&'static Emoji]>,
+    shown: usize,
 }
 
-impl TantivySearch {
-    fn update_index(index: &Index, langs: &[&str], annotation: Field, glyph: Field) {
-        let mut index_writer = index.writer(15_000_000).unwrap();
-
-        for emoji in iter_emoji() {
-            let mut full_annotation = String::new();
-            for annotation in emoji.annotations {
-                if langs.contains(&annotation.lang) {
-                    full_annotation.push_str(&annotation.keywords.join(","));
-                }
-            }
-            index_writer
-                .add_document(doc!(
-                    annotation => full_annotation,
-                    glyph =>emoji.glyph
-                ))
-                .unwrap();
+impl SearchResults {
+    fn new(all: Vec<&'static Emoji>, page_size: usize) -> Self {
+        Self {
+            shown: page_size.min(all.len()),
+            all: all.into(),
         }
+    }
+
+    /// The pages shown so far
+    pub fn emojis(&self) -> &[&'static Emoji] {
+        &self.all[..self.shown]
+    }
 
-        index_writer.commit().unwrap();
+    /// How many there are in all pages
+    pub fn total(&self) -> usize {
+        self.all.len()
     }
 
-    fn extract_fields(index: &Index) -> DataPair {
-        let annotation = index.schema().find_field("annotation").unwrap().0;
-        let glyph = index.schema().find_field("glyph").unwrap().0;
-        DataPair { annotation, glyph }
+    /// Shows one more page, false if there were no more
+    pub fn show_more(&mut self, page_size: usize) -> bool {
+        let shown = (self.shown + page_size).min(self.all.len());
+        let more = shown > self.shown;
+        self.shown = shown;
+        more
     }
+}
 
-    pub fn new(langs: &[&str]) -> Self {
-        const INDEX_PATH: &str = "./index";
+/// Emojis a search is restricted to
+#[derive(Debug, Clone, Default)]
+pub enum Scope {
+    #[default]
+    All,
+    Group(&'static str),
+    /// Untoned glyphs, any tone of them is in scope
+    Glyphs(Vec<&'static str>),
+}
 
-        fn has_index(path: &str) -> bool {
-            use tantivy::directory::error::OpenDirectoryError;
-            match MmapDirectory::open(path) {
-                Ok(mmapdir) => Index::exists(&mmapdir).unwrap(),
-                Err(OpenDirectoryError::DoesNotExist(_)) => {
-                    std::fs::create_dir_all(INDEX_PATH).unwrap();
-                    false
-                }
-                Err(a) => panic!("Error while opening index dir: {a}"),
-            }
+impl Scope {
+    fn contains(&self, emoji: &'static Emoji) -> bool {
+        match self {
+            Scope::All => true,
+            Scope::Group(group) => emoji.group == *group,
+            Scope::Glyphs(glyphs) => glyphs.contains(&variants::untoned(emoji).glyph),
         }
+    }
+}
 
-        let (index, annotation, glyph) = {
-            if has_index(INDEX_PATH) {
-                let index = Index::open_in_dir(INDEX_PATH).unwrap();
-                let data_pair = Self::extract_fields(&index);
-                (index, data_pair.annotation, data_pair.glyph)
-            } else {
-                let mut schema_builder = Schema::builder();
-                let annotation = schema_builder.add_text_field("annotation", TEXT);
-                let glyph = schema_builder.add_text_field("glyph", TEXT | STORED);
-                let schema = schema_builder.build();
+pub trait SearchEngine {
+    /// Everything found, with the first `page_size` of them shown
+    fn search_emojis(&self, emoji: &str, scope: &Scope, page_size: usize) -> SearchResults;
+}
 
-                let index = Index::create_in_dir(INDEX_PATH, schema.clone()).unwrap();
+pub struct TantivySearch {
+    langs: Vec<LangSearch>,
+}
 
-                (index, annotation, glyph)
-            }
-        };
+impl fmt::Debug for TantivySearch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TantivySearch")
+            .field("indexes", &self.langs.len())
+            .finish()
+    }
+}
+
+struct LangSearch {
+    index: Index,
+    // Annotations are split in pieces, see index::is_cjk
+    in_ngrams: bool,
+    // Scores are multiplied by it, the less preferred the language the lower
+    boost: Score,
+    searcher: Searcher,
+    annotation: Field,
+    annotation_folded: Field,
+    annotation_stemmed: Option<Field>,
+    glyph: Field,
+    group: Field,
+    subgroup: Field,
+    version: Field,
+    is_variant: Field,
+}
+
+fn term_query(term: Term) -> Box<dyn tantivy::query::Query> {
+    Box::new(TermQuery::new(term, IndexRecordOption::WithFreqs))
+}
 
-        Self::update_index(&index, langs, annotation, glyph);
-        let query_parser = QueryParser::for_index(&index, vec![annotation, glyph]);
+impl LangSearch {
+    fn new(index: &Index, lang: &str, boost: Score) -> Self {
+        let schema = index.schema();
+        let field = |name| schema.get_field(name).unwrap();
+        index::register_tokenizers(index);
 
-        let reader = index.reader().unwrap();
+        // Indexes are never written once opened, no need to watch them
+        let reader = index
+            .reader_builder()
+            .reload_policy(ReloadPolicy::Manual)
+            .try_into()
+            .unwrap();
         let searcher = reader.searcher();
 
         Self {
+            index: index.clone(),
+            in_ngrams: index::is_cjk(lang),
+            boost,
             searcher,
-            glyph,
-            query_parser,
+            annotation: field(index::ANNOTATION),
+            annotation_folded: field(index::ANNOTATION_FOLDED),
+            annotation_stemmed: schema.get_field(index::ANNOTATION_STEMMED).ok(),
+            glyph: field(index::GLYPH),
+            group: field(index::GROUP),
+            subgroup: field(index::SUBGROUP),
+            version: field(index::VERSION),
+            is_variant: field(index::IS_VARIANT),
         }
     }
-}
 
-impl SearchEngine for TantivySearch {
-    fn search_emojis(&self, emoji: &str, max_count: u32) -> Vec<&'static Emoji> {
-        use emoji::lookup_by_glyph::lookup;
+    // Words of the text as they are in the index, anything else is dropped
+    // so that there's nothing that could fail to parse
+    fn terms(&self, field: Field, text: &str) -> Vec<Term> {
+        let mut tokenizer = self.index.tokenizer_for_field(field).unwrap();
+        let mut terms = Vec::new();
+        tokenizer
+            .token_stream(text)
+            .process(&mut |token| terms.push(Term::from_field_text(field, &token.text)));
+        terms
+    }
 
-        let Ok(query) = self.query_parser.parse_query(emoji) else {
-            return Vec::new();
+    // Emojis with every word in the field
+    fn all_words(&self, field: Field, text: &str) -> Option<Box<dyn tantivy::query::Query>> {
+        let terms = self.terms(field, text);
+        if terms.is_empty() {
+            return None;
+        }
+
+        Some(Box::new(BooleanQuery::new(
+            terms
+                .into_iter()
+                .map(|term| (Occur::Must, term_query(term)))
+                .collect(),
+        )))
+    }
+
+    // Any of the words will do, though those with all of them come first and
+    // then those written the same, accents and all. Other forms of the words
+    // count for less
+    fn any_words(&self, text: &str) -> Option<Box<dyn tantivy::query::Query>> {
+        let all = self.all_words(self.annotation_folded, text)?;
+        let exact_terms = if self.in_ngrams {
+            // Having every piece is as close as it gets to having the word
+            self.all_words(self.annotation, text).into_iter().collect()
+        } else {
+            self.terms(self.annotation, text)
+                .into_iter()
+                .map(term_query)
+                .collect::<Vec<_>>()
+        };
+        let exact = exact_terms.into_iter().map(|query| {
+            (
+                Occur::Should,
+                Box::new(BoostQuery::new(query, EXACT_BOOST)) as Box<_>,
+            )
+        });
+        let stemmed = self
+            .annotation_stemmed
+            .map(|field| self.terms(field, text))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|term| {
+                (
+                    Occur::Should,
+                    Box::new(BoostQuery::new(term_query(term), STEMMED_BOOST)) as Box<_>,
+                )
+            });
+
+        Some(Box::new(BooleanQuery::new(
+            self.terms(self.annotation_folded, text)
+                .into_iter()
+                .map(|term| (Occur::Should, term_query(term)))
+                .chain(exact)
+                .chain(stemmed)
+                .chain(std::iter::once((
+                    Occur::Should,
+                    Box::new(BoostQuery::new(all, ALL_WORDS_BOOST)) as Box<_>,
+                )))
+                .collect(),
+        )))
+    }
+
+    fn to_tantivy(&self, query: &Query) -> Option<BooleanQuery> {
+        let words = if query.text.is_empty() {
+            Box::new(AllQuery)
+        } else {
+            self.any_words(&query.text)?
         };
+        let mut clauses = vec![(Occur::Must, words)];
+
+        // Filters without any word in them are ignored
+        for filter in &query.filters {
+            let clause = match filter {
+                Filter::Group(group) => self.all_words(self.group, group).map(|q| (Occur::Must, q)),
+                Filter::Subgroup(subgroup) => self
+                    .all_words(self.subgroup, subgroup)
+                    .map(|q| (Occur::Must, q)),
+                Filter::Version(lower, upper) => {
+                    let term = |v: &f64| Term::from_field_f64(self.version, *v);
+                    Some((
+                        Occur::Must,
+                        Box::new(RangeQuery::new(
+                            lower.as_ref().map(term),
+                            upper.as_ref().map(term),
+                        )) as Box<_>,
+                    ))
+                }
+                Filter::Variant(is_variant) => Some((
+                    Occur::Must,
+                    term_query(Term::from_field_bool(self.is_variant, *is_variant)),
+                )),
+                // Left out once every language is searched, see excluded
+                Filter::Exclude(_) => None,
+            };
+            clauses.extend(clause);
+        }
+
+        Some(BooleanQuery::new(clauses))
+    }
+
+    fn search(&self, query: &Query) -> Vec<(Score, &'static Emoji)> {
+        match self.to_tantivy(query) {
+            Some(query) => self.matching(&query),
+            None => Vec::new(),
+        }
+    }
 
+    // Those with any of the keywords to leave out, in this language
+    fn excluded(&self, query: &Query) -> Vec<&'static Emoji> {
+        let clauses = query
+            .filters
+            .iter()
+            .filter_map(|filter| match filter {
+                Filter::Exclude(keyword) => self.all_words(self.annotation_folded, keyword),
+                _ => None,
+            })
+            .map(|q| (Occur::Should, q))
+            .collect::<Vec<_>>();
+        if clauses.is_empty() {
+            return Vec::new();
+        }
+        self.matching(&BooleanQuery::new(clauses))
+            .into_iter()
+            .map(|(_, emoji)| emoji)
+            .collect()
+    }
+
+    // Everything that matches, it's never more than the emojis we have
+    fn matching(&self, query: &dyn tantivy::query::Query) -> Vec<(Score, &'static Emoji)> {
         let top_docs: Vec<(Score, DocAddress)> = self
             .searcher
-            .search(&query, &TopDocs::with_limit(max_count as usize))
+            .search(
+                query,
+                &TopDocs::with_limit(self.searcher.num_docs().max(1) as usize),
+            )
             .unwrap();
 
+        // User keywords could be given to glyphs we know nothing about
         top_docs
             .into_iter()
-            .map(|(_, doc_address)| {
+            .filter_map(|(score, doc_address)| {
                 let retrieved_doc: TantivyDocument = self.searcher.doc(doc_address).unwrap();
                 let a = retrieved_doc
                     .get_first(self.glyph)
                     .unwrap()
                     .as_str()
                     .unwrap();
-                lookup(a).unwrap()
+                Some((score * self.boost, lookup(a)?))
             })
             .collect()
     }
 }
+
+impl TantivySearch {
+    // The index is built along with bmoji and read from the binary itself
+    fn open_prebuilt(lang: &str) -> Option<Index> {
+        let (_, files) = prebuilt::INDEXES.iter().find(|(l, _)| *l == lang)?;
+        Some(Index::open(ReadOnlyDirectory::Embedded(files)).unwrap())
+    }
+
+    // Installed indexes are preferred, they can be updated without a rebuild
+    fn open_installed(data_dir: &Path, lang: &str) -> Option<Index> {
+        let directory = MmapDirectory::open(data_dir.join("index").join(lang)).ok()?;
+        // Left behind by another version of bmoji
+        Index::open(ReadOnlyDirectory::Installed(directory))
+            .ok()
+            .filter(|index| index.schema() == index::schema(lang))
+    }
+
+    /// Index of the user's own keywords, rebuilt into the cache whenever they
+    /// are changed or it was built by another version of bmoji
+    fn open_user(keywords_path: &Path, cache_path: &Path) -> Option<Index> {
+        let changed = fs::metadata(keywords_path)
+            .and_then(|m| m.modified())
+            .ok()?;
+        let is_fresh = fs::metadata(cache_path.join("meta.json"))
+            .and_then(|m| m.modified())
+            .is_ok_and(|built| built >= changed);
+        if is_fresh {
+            if let Ok(index) = Index::open_in_dir(cache_path) {
+                if index.schema() == index::schema(USER_LANG) {
+                    return Some(index);
+                }
+            }
+        }
+
+        let keywords: HashMap<String, Vec<String>> =
+            serde_json::from_reader(BufReader::new(File::open(keywords_path).ok()?)).ok()?;
+        let known = keywords
+            .iter()
+            .filter_map(|(glyph, words)| match lookup(glyph) {
+                Some(emoji) => Some((emoji, words.join(","))),
+                None => {
+                    eprintln!("Keywords for {glyph:?} left out, it isn't an emoji bmoji knows");
+                    None
+                }
+            });
+        index::write_keywords(cache_path, USER_LANG, known).ok()?;
+
+        Index::open_in_dir(cache_path).ok()
+    }
+
+    /// Searches in the given languages, the first ones are preferred
+    pub fn new(langs: &[&str], keywords_path: &Path, cache_path: &Path) -> Self {
+        Self::open(Path::new(DATA_DIR), langs, keywords_path, cache_path)
+    }
+
+    fn open(data_dir: &Path, langs: &[&str], keywords_path: &Path, cache_path: &Path) -> Self {
+        let langs = langs
+            .iter()
+            .enumerate()
+            .filter_map(|(pos, lang)| {
+                let index =
+                    Self::open_installed(data_dir, lang).or_else(|| Self::open_prebuilt(lang))?;
+                let boost = 1.0 / (1.0 + LANG_BOOST_STEP * pos as Score);
+                Some(LangSearch::new(&index, lang, boost))
+            })
+            .chain(
+                Self::open_user(keywords_path, cache_path)
+                    .map(|index| LangSearch::new(&index, USER_LANG, 1.0)),
+            )
+            .collect();
+
+        Self { langs }
+    }
+}
+
+impl SearchEngine for TantivySearch {
+    fn search_emojis(&self, emoji: &str, scope: &Scope, page_size: usize) -> SearchResults {
+        let query = Query::parse(emoji);
+        // The same emoji can be found in several languages, keep its best score
+        let mut found: HashMap<&'static str, (Score, &'static Emoji)> = HashMap::new();
+        let langs = if query.is_only_emoticons() {
+            &[][..]
+        } else {
+            &self.langs
+        };
+        for lang in langs {
+            for (score, emoji) in lang
+                .search(&query)
+                .into_iter()
+                .filter_map(|(score, e)| Some((score, in_tone(e, query.tone)?)))
+                .filter(|(_, e)| scope.contains(e))
+            {
+                let best = found.entry(emoji.glyph).or_insert((score, emoji));
+                best.0 = best.0.max(score);
+            }
+        }
+        // A keyword left out in any language is left out of them all, even
+        // if another one finds the emoji without it
+        let excluded = langs
+            .iter()
+            .flat_map(|lang| lang.excluded(&query))
+            .map(|e| variants::untoned(e).glyph)
+            .collect::<HashSet<_>>();
+        found.retain(|_, (_, e)| !excluded.contains(variants::untoned(e).glyph));
+
+        let mut results = found.into_values().collect::<Vec<_>>();
+        results.sort_by(|a, b| b.0.total_cmp(&a.0).then(a.1.glyph.cmp(b.1.glyph)));
+        SearchResults::new(
+            with_emoticons(&query, scope, results.into_iter().map(|(_, emoji)| emoji)),
+            page_size,
+        )
+    }
+}
+
+// Emoticons typed go first, then everything else without repeating them
+fn with_emoticons(
+    query: &Query,
+    scope: &Scope,
+    found: impl Iterator<Item = &'static Emoji>,
+) -> Vec<&'static Emoji> {
+    let mut results = query
+        .emoticons
+        .iter()
+        .filter_map(|e| in_tone(e, query.tone))
+        .filter(|e| scope.contains(e))
+        .collect::<Vec<_>>();
+    for emoji in found {
+        if !results.contains(&emoji) {
+            results.push(emoji);
+        }
+    }
+    results
+}
+
+// Emojis that come in skin tones, in the one asked for
+fn in_tone(emoji: &'static Emoji, tone: Option<SkinTone>) -> Option<&'static Emoji> {
+    match tone {
+        Some(tone) => variants::family_of(emoji).map(|family| family.with_tone(tone)),
+        None => Some(emoji),
+    }
+}
+
+/// Plain scan of the English names, good enough while the indexes load
+pub struct NameSearch;
+
+impl SearchEngine for NameSearch {
+    fn search_emojis(&self, emoji: &str, scope: &Scope, page_size: usize) -> SearchResults {
+        let query = Query::parse(emoji);
+        let text = query.text.to_lowercase();
+        let words = text
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .collect::<Vec<_>>();
+
+        // Like the indexes, any word will do but those with more come first
+        let emojis = if query.is_only_emoticons() {
+            Vec::new()
+        } else {
+            iter_emoji().collect()
+        };
+        let mut found = emojis
+            .into_iter()
+            .filter(|e| {
+                e.status == Status::FullyQualified
+                    && !e.is_variant
+                    && !variants::is_toned(e)
+                    && query.filters.iter().all(|f| f.matches(e))
+            })
+            .filter_map(|e| {
+                let matched = words.iter().filter(|w| e.name.contains(*w)).count();
+                (text.is_empty() || matched > 0).then_some((matched, e))
+            })
+            .filter_map(|(matched, e)| Some((matched, in_tone(e, query.tone)?)))
+            .filter(|(_, e)| scope.contains(e))
+            .collect::<Vec<_>>();
+        // Same order as the grid between those as good as each other
+        found.sort_by_cached_key(|(matched, e)| {
+            (std::cmp::Reverse(*matched), groups::grid_order(e))
+        });
+        SearchResults::new(
+            with_emoticons(&query, scope, found.into_iter().map(|(_, e)| e)),
+            page_size,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pages_are_shown_from_what_was_found() {
+        let all = ["😀", "🐱", "🍕", "🚗", "⚽"].map(|glyph| lookup(glyph).unwrap());
+        let mut results = SearchResults::new(all.to_vec(), 2);
+        assert_eq!(results.emojis(), &all[..2]);
+        assert_eq!(results.total(), 5);
+
+        assert!(results.show_more(2));
+        assert!(results.show_more(2));
+        assert_eq!(results.emojis(), &all[..]);
+        assert!(!results.show_more(2));
+    }
+
+    // Installed the way --write-index does, so it works without embedded indexes
+    fn installed(langs: &[&str], keywords: &str) -> (tempfile::TempDir, TantivySearch) {
+        let dir = tempfile::tempdir().unwrap();
+        index::write_indexes(&dir.path().join("index"), langs.iter().copied()).unwrap();
+        let keywords_path = dir.path().join("keywords.json");
+        fs::write(&keywords_path, keywords).unwrap();
+        let cache_path = dir.path().join("cache");
+        let search = TantivySearch::open(dir.path(), langs, &keywords_path, &cache_path);
+        (dir, search)
+    }
+
+    #[test]
+    fn indexes_find_versions_as_written() {
+        let (_dir, search) = installed(&["en"], "{}");
+        let results = search.search_emojis("heart version:13.1", &Scope::All, 100);
+        assert!(results
+            .emojis()
+            .contains(&lookup("❤\u{fe0f}\u{200d}🔥").unwrap()));
+        assert!(results
+            .emojis()
+            .iter()
+            .all(|e| e.introduction_version == 13.1));
+    }
+
+    #[test]
+    fn words_left_out_in_one_language_are_left_out_of_all() {
+        let (_dir, search) = installed(&["en"], r#"{"🦀": ["ferris"]}"#);
+        let crab = lookup("🦀").unwrap();
+        let found = |query| search.search_emojis(query, &Scope::All, 100);
+        assert!(found("ferris").emojis().contains(&crab));
+        assert!(!found("ferris -crab").emojis().contains(&crab));
+        assert!(!found("cat -face").emojis().contains(&lookup("🐱").unwrap()));
+    }
+}
@@ -8,20 +8,15 @@ pub struct RoundedTheme {
     pub accent_color: iced::Color,
 }
 
-#[derive(PartialEq)]
+#[derive(PartialEq, Default)]
 pub enum ButtonStyle {
     Category,
     Emoji,
+    #[default]
     Plain,
     ClearSearch,
 }
 
-impl Default for ButtonStyle {
-    fn default() -> Self {
-        ButtonStyle::Plain
-    }
-}
-
 impl iced::widget::button::Catalog for RoundedTheme {
     type Class<'a> = ButtonStyle;
 
@@ -0,0 +1,489 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use emoji::{lookup_by_glyph::iter_emoji, Emoji, Status};
+use serde::{Deserialize, Serialize};
+
+/// Fitzpatrick skin tones, in the order the emoji data uses them
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum SkinTone {
+    Light,
+    MediumLight,
+    Medium,
+    MediumDark,
+    Dark,
+}
+
+impl SkinTone {
+    pub const ALL: [SkinTone; 5] = [
+        SkinTone::Light,
+        SkinTone::MediumLight,
+        SkinTone::Medium,
+        SkinTone::MediumDark,
+        SkinTone::Dark,
+    ];
+
+    /// The modifier character, shown on its own it's a swatch of the tone
+    pub fn modifier(self) -> &'static str {
+        match self {
+            SkinTone::Light => "\u{1F3FB}",
+            SkinTone::MediumLight => "\u{1F3FC}",
+            SkinTone::Medium => "\u{1F3FD}",
+            SkinTone::MediumDark => "\u{1F3FE}",
+            SkinTone::Dark => "\u{1F3FF}",
+        }
+    }
+
+    pub fn from_qualifier(qualifier: &str) -> Option<Self> {
+        match qualifier {
+            "light skin tone" => Some(SkinTone::Light),
+            "medium-light skin tone" => Some(SkinTone::MediumLight),
+            "medium skin tone" => Some(SkinTone::Medium),
+            "medium-dark skin tone" => Some(SkinTone::MediumDark),
+            "dark skin tone" => Some(SkinTone::Dark),
+            _ => None,
+        }
+    }
+}
+
+/// Person forms of people emojis, in the order we offer them
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum Gender {
+    Person,
+    Man,
+    Woman,
+}
+
+impl Gender {
+    pub const ALL: [Gender; 3] = [Gender::Person, Gender::Man, Gender::Woman];
+
+    /// Splits "woman technologist" into the gender and what's left of the name
+    fn split_name(name: &str) -> Option<(Self, &str)> {
+        for (gender, prefix) in [
+            (Gender::Person, "person"),
+            (Gender::Man, "man"),
+            (Gender::Woman, "woman"),
+        ] {
+            if let Some(stem) = name.strip_prefix(prefix) {
+                if stem.is_empty() || stem.starts_with(' ') || stem.starts_with(':') {
+                    return Some((gender, stem.trim_start()));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Preferred variants, used when showing or copying an emoji
+#[derive(Debug, Copy, Clone, Default, Deserialize, Serialize)]
+pub struct Preference {
+    #[serde(default)]
+    pub skin_tone: Option<SkinTone>,
+    #[serde(default)]
+    pub gender: Option<Gender>,
+}
+
+/// Splits a CLDR name like "kiss: man, man, light skin tone" into its base
+/// ("kiss: man, man") and the tones it carries
+fn split_name(name: &str) -> (String, Vec<SkinTone>) {
+    let Some((base, qualifiers)) = name.split_once(": ") else {
+        return (name.to_string(), Vec::new());
+    };
+
+    let mut attrs = Vec::new();
+    let mut tones = Vec::new();
+    for qualifier in qualifiers.split(", ") {
+        match SkinTone::from_qualifier(qualifier) {
+            Some(tone) => tones.push(tone),
+            // "kiss: person, person" is just called "kiss"
+            None if qualifier == "person" => {}
+            None => attrs.push(qualifier),
+        }
+    }
+
+    if attrs.is_empty() {
+        (base.to_string(), tones)
+    } else {
+        (format!("{base}: {}", attrs.join(", ")), tones)
+    }
+}
+
+/// An emoji together with all of its skin toned forms
+#[derive(Debug)]
+pub struct ToneFamily {
+    pub base: &'static Emoji,
+    pub persons: usize,
+    by_tones: HashMap<Vec<SkinTone>, &'static Emoji>,
+}
+
+impl ToneFamily {
+    fn uniform(&self, tone: SkinTone) -> Option<&'static Emoji> {
+        self.by_tones.get(&vec![tone; self.persons]).copied()
+    }
+
+    /// The form of this emoji with one tone per person, in order
+    pub fn with_tones(&self, tones: &[SkinTone]) -> Option<&'static Emoji> {
+        self.by_tones.get(tones).copied()
+    }
+
+    /// The form of this emoji where everyone has the same tone
+    pub fn with_tone(&self, tone: SkinTone) -> &'static Emoji {
+        self.uniform(tone).unwrap_or(self.base)
+    }
+
+    /// Preferred form of this emoji, the base one when no tone is given
+    pub fn with_tone_opt(&self, tone: Option<SkinTone>) -> &'static Emoji {
+        tone.map_or(self.base, |tone| self.with_tone(tone))
+    }
+
+    /// The base emoji followed by every uniformly toned form
+    pub fn members(&self) -> Vec<&'static Emoji> {
+        std::iter::once(self.base)
+            .chain(SkinTone::ALL.iter().filter_map(|tone| self.uniform(*tone)))
+            .collect()
+    }
+}
+
+// Some forms are listed twice, once on their own and once as a variant of
+// another one, so the ones on their own come first and take precedence
+fn fully_qualified() -> Vec<&'static Emoji> {
+    let mut emojis = iter_emoji()
+        .filter(|e| e.status == Status::FullyQualified)
+        .collect::<Vec<_>>();
+    emojis.sort_by_key(|e| e.is_variant);
+    emojis
+}
+
+struct Families {
+    families: Vec<ToneFamily>,
+    by_glyph: HashMap<&'static str, usize>,
+}
+
+static FAMILIES: LazyLock<Families> = LazyLock::new(|| {
+    let mut bases: HashMap<String, &'static Emoji> = HashMap::new();
+    let mut toned: HashMap<String, Vec<(Vec<SkinTone>, &'static Emoji)>> = HashMap::new();
+
+    for emoji in fully_qualified() {
+        let (base, tones) = split_name(emoji.name);
+        if tones.is_empty() {
+            bases.entry(base).or_insert(emoji);
+        } else {
+            toned.entry(base).or_default().push((tones, emoji));
+        }
+    }
+
+    let mut families = Vec::new();
+    let mut by_glyph = HashMap::new();
+    for (base_name, forms) in toned {
+        let Some(base) = bases.get(&base_name).copied() else {
+            continue;
+        };
+
+        let persons = forms.iter().map(|(t, _)| t.len()).max().unwrap_or(1);
+        let mut by_tones = HashMap::new();
+        for (tones, emoji) in forms {
+            // Multi-person emojis name everyone once when all share a tone
+            let tones = if tones.len() < persons {
+                vec![tones[0]; persons]
+            } else {
+                tones
+            };
+            by_tones.entry(tones).or_insert(emoji);
+        }
+
+        let idx = families.len();
+        by_glyph.insert(base.glyph, idx);
+        for emoji in by_tones.values() {
+            by_glyph.insert(emoji.glyph, idx);
+        }
+        families.push(ToneFamily {
+            base,
+            persons,
+            by_tones,
+        });
+    }
+
+    Families { families, by_glyph }
+});
+
+/// The person, man and woman forms of an emoji, untoned
+#[derive(Debug)]
+pub struct GenderGroup {
+    by_gender: Vec<(Gender, &'static Emoji)>,
+}
+
+impl GenderGroup {
+    /// The form that stands for the whole group in the grid
+    pub fn lead(&self) -> &'static Emoji {
+        self.by_gender[0].1
+    }
+
+    pub fn with_gender(&self, gender: Gender) -> Option<&'static Emoji> {
+        self.by_gender
+            .iter()
+            .find(|(g, _)| *g == gender)
+            .map(|(_, emoji)| *emoji)
+    }
+
+    pub fn members(&self) -> &[(Gender, &'static Emoji)] {
+        &self.by_gender
+    }
+}
+
+struct GenderGroups {
+    groups: Vec<GenderGroup>,
+    by_glyph: HashMap<&'static str, usize>,
+}
+
+static GENDER_GROUPS: LazyLock<GenderGroups> = LazyLock::new(|| {
+    let mut stems: HashMap<&'static str, Vec<(Gender, &'static Emoji)>> = HashMap::new();
+    let untoned = fully_qualified()
+        .into_iter()
+        .filter(|e| !is_toned(e))
+        .collect::<Vec<_>>();
+
+    for emoji in &untoned {
+        if let Some((gender, stem)) = Gender::split_name(emoji.name) {
+            let members = stems.entry(stem).or_default();
+            if members.iter().all(|(g, _)| *g != gender) {
+                members.push((gender, emoji));
+            }
+        }
+    }
+
+    // Some person forms have no prefix at all ("technologist")
+    for emoji in &untoned {
+        if let Some(members) = stems.get_mut(emoji.name) {
+            if members.iter().all(|(g, _)| *g != Gender::Person) {
+                members.push((Gender::Person, emoji));
+            }
+        }
+    }
+
+    let mut groups = Vec::new();
+    let mut by_glyph = HashMap::new();
+    for (_, mut by_gender) in stems {
+        if by_gender.len() < 2 {
+            continue;
+        }
+
+        by_gender.sort_by_key(|(gender, _)| Gender::ALL.iter().position(|g| g == gender));
+        let idx = groups.len();
+        for (_, emoji) in &by_gender {
+            by_glyph.insert(emoji.glyph, idx);
+        }
+        groups.push(GenderGroup { by_gender });
+    }
+
+    GenderGroups { groups, by_glyph }
+});
+
+/// Person, man and woman forms this emoji is one of, toned ones included
+pub fn gender_group_of(emoji: &Emoji) -> Option<&'static GenderGroup> {
+    let base = family_of(emoji).map_or(emoji.glyph, |family| family.base.glyph);
+    let groups = &*GENDER_GROUPS;
+    groups.by_glyph.get(base).map(|idx| &groups.groups[*idx])
+}
+
+/// Gender of this emoji, as long as it's part of a group
+pub fn gender_of(emoji: &Emoji) -> Option<Gender> {
+    let base = family_of(emoji).map_or(emoji.glyph, |family| family.base.glyph);
+    gender_group_of(emoji)?
+        .members()
+        .iter()
+        .find(|(_, e)| e.glyph == base)
+        .map(|(gender, _)| *gender)
+}
+
+/// Whether this emoji is shown through another one of its gender group
+pub fn is_grouped_out(emoji: &Emoji) -> bool {
+    gender_group_of(emoji).is_some_and(|group| group.lead().glyph != emoji.glyph)
+}
+
+/// Family of skin tones this emoji belongs to, if any
+pub fn family_of(emoji: &Emoji) -> Option<&'static ToneFamily> {
+    let families = &*FAMILIES;
+    families
+        .by_glyph
+        .get(emoji.glyph)
+        .map(|idx| &families.families[*idx])
+}
+
+/// Whether this emoji is a skin toned form of another one
+pub fn is_toned(emoji: &Emoji) -> bool {
+    !split_name(emoji.name).1.is_empty()
+}
+
+/// Skin tone of this emoji, as long as everyone in it has the same one
+pub fn tone_of(emoji: &Emoji) -> Option<SkinTone> {
+    let (_, tones) = split_name(emoji.name);
+    let first = *tones.first()?;
+    tones.iter().all(|t| *t == first).then_some(first)
+}
+
+/// Whether there's anything to choose from for this emoji
+pub fn has_variants(emoji: &Emoji) -> bool {
+    emoji.variants.len() > 1 || family_of(emoji).is_some() || gender_group_of(emoji).is_some()
+}
+
+/// Untoned form of this emoji
+pub fn untoned(emoji: &'static Emoji) -> &'static Emoji {
+    family_of(emoji).map_or(emoji, |family| family.base)
+}
+
+/// Applies the preferred variants to an emoji. Only the leads of gender
+/// groups change gender, and those already toned are left as they are
+pub fn preferred(emoji: &'static Emoji, preference: Preference) -> &'static Emoji {
+    if is_toned(emoji) {
+        return emoji;
+    }
+
+    let emoji = match (gender_group_of(emoji), preference.gender) {
+        (Some(group), Some(gender)) if group.lead().glyph == emoji.glyph => {
+            group.with_gender(gender).unwrap_or(emoji)
+        }
+        _ => emoji,
+    };
+
+    match family_of(emoji) {
+        Some(family) => family.with_tone_opt(preference.skin_tone),
+        None => emoji,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use emoji::lookup_by_glyph::lookup;
+
+    use super::*;
+
+    fn emoji(glyph: &str) -> &'static Emoji {
+        lookup(glyph).unwrap_or_else(|| panic!("{glyph:?} isn't an emoji"))
+    }
+
+    #[test]
+    fn names_are_split_into_base_and_tones() {
+        use SkinTone::*;
+        let tests: &[(&str, &str, &[SkinTone])] = &[
+            ("waving hand", "waving hand", &[]),
+            ("waving hand: medium skin tone", "waving hand", &[Medium]),
+            ("kiss: person, person", "kiss", &[]),
+            (
+                "kiss: person, person, light skin tone, dark skin tone",
+                "kiss",
+                &[Light, Dark],
+            ),
+            ("kiss: man, man", "kiss: man, man", &[]),
+            (
+                "kiss: woman, man, medium-light skin tone, medium-dark skin tone",
+                "kiss: woman, man",
+                &[MediumLight, MediumDark],
+            ),
+            ("flag: Spain", "flag: Spain", &[]),
+        ];
+        for (name, base, tones) in tests {
+            assert_eq!(
+                split_name(name),
+                (base.to_string(), tones.to_vec()),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn genders_are_only_whole_words() {
+        let tests = [
+            ("woman technologist", Some((Gender::Woman, "technologist"))),
+            ("man", Some((Gender::Man, ""))),
+            ("person: beard", Some((Gender::Person, ": beard"))),
+            ("man's shoe", None),
+            ("mango", None),
+            ("technologist", None),
+        ];
+        for (name, split) in tests {
+            assert_eq!(Gender::split_name(name), split, "{name}");
+        }
+    }
+
+    #[test]
+    fn two_person_emojis_take_a_tone_each() {
+        // Handshakes only come in one tone in the emoji data we use
+        assert!(family_of(emoji("🤝")).is_none());
+
+        let holding_hands = family_of(emoji("🧑\u{200d}🤝\u{200d}🧑")).unwrap();
+        assert_eq!(holding_hands.persons, 2);
+        assert_eq!(
+            holding_hands.with_tones(&[SkinTone::Light, SkinTone::Dark]),
+            Some(emoji("🧑🏻\u{200d}🤝\u{200d}🧑🏿"))
+        );
+        assert_eq!(
+            holding_hands.with_tone(SkinTone::Medium),
+            emoji("🧑🏽\u{200d}🤝\u{200d}🧑🏽")
+        );
+
+        let kiss = family_of(emoji("💏")).unwrap();
+        assert_eq!(kiss.persons, 2);
+        assert_eq!(
+            kiss.with_tones(&[SkinTone::Light, SkinTone::Dark]),
+            Some(emoji("🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿"))
+        );
+
+        assert_eq!(family_of(emoji("👋")).unwrap().persons, 1);
+        assert!(family_of(emoji("🐱")).is_none());
+    }
+
+    #[test]
+    fn gender_groups_are_led_by_the_person() {
+        let technologist = emoji("🧑\u{200d}💻");
+        let group = gender_group_of(emoji("👩\u{200d}💻")).unwrap();
+        assert_eq!(group.lead(), technologist);
+        assert_eq!(group.with_gender(Gender::Man), Some(emoji("👨\u{200d}💻")));
+        assert_eq!(gender_of(technologist), Some(Gender::Person));
+        assert!(is_grouped_out(emoji("👩\u{200d}💻")));
+        assert!(!is_grouped_out(technologist));
+
+        // Toned forms belong to the group of their base
+        assert_eq!(gender_of(emoji("👩🏿\u{200d}💻")), Some(Gender::Woman));
+
+        assert!(gender_group_of(emoji("👞")).is_none());
+        assert!(gender_group_of(emoji("🥭")).is_none());
+    }
+
+    #[test]
+    fn preferences_apply_gender_then_tone() {
+        let preference = |gender, skin_tone| Preference { skin_tone, gender };
+        let tests = [
+            ("🧑\u{200d}💻", preference(None, None), "🧑\u{200d}💻"),
+            (
+                "🧑\u{200d}💻",
+                preference(Some(Gender::Woman), None),
+                "👩\u{200d}💻",
+            ),
+            (
+                "🧑\u{200d}💻",
+                preference(Some(Gender::Man), Some(SkinTone::Dark)),
+                "👨🏿\u{200d}💻",
+            ),
+            (
+                "👋",
+                preference(Some(Gender::Woman), Some(SkinTone::Light)),
+                "👋🏻",
+            ),
+            // Already chosen forms are kept as they are
+            (
+                "👩🏽\u{200d}💻",
+                preference(Some(Gender::Man), Some(SkinTone::Dark)),
+                "👩🏽\u{200d}💻",
+            ),
+            (
+                "🐱",
+                preference(Some(Gender::Man), Some(SkinTone::Dark)),
+                "🐱",
+            ),
+        ];
+        for (glyph, preference, shown) in tests {
+            assert_eq!(preferred(emoji(glyph), preference).glyph, shown, "{glyph}");
+        }
+    }
+}